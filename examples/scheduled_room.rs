@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use dailyco::RoomPropertiesBuilder;
+
+#[tokio::main]
+async fn main() -> dailyco::Result<()> {
+    let client = dailyco::Client::new("test-api-key")?;
+
+    // A private room for a half hour call, which `Daily` cleans up once it is over.
+    // Everyone still in the room is ejected when it expires.
+    let link = client
+        .create_ephemeral_room(
+            Duration::from_secs(30 * 60),
+            RoomPropertiesBuilder::new()
+                .max_participants(4)
+                .enable_knocking(true),
+        )
+        .await?;
+
+    // The owner token expires along with the room, so the link can be handed to the
+    // host as is.
+    println!("Host link: {}", link.url);
+
+    // Rooms can still be removed early, e.g. if the call is cancelled
+    client.delete_room(&link.room.name).await?;
+    Ok(())
+}
@@ -0,0 +1,442 @@
+//! A trait over the `Daily` API, to depend on instead of [Client] directly.
+use std::future::Future;
+use std::time::Duration;
+
+use bytes::Bytes;
+use uuid::Uuid;
+
+use crate::logs::MeetingLogs;
+use crate::meeting_token::{
+    CreateMeetingToken, MeetingToken, MeetingTokenString, TokenPermissions,
+};
+use crate::pagination::Page;
+use crate::presence::{PresenceParticipant, Session};
+use crate::recording::{
+    GetRecordingAccessLink, ListRecordings, ListedRecordings, RecordingAccessLink, RecordingId,
+    RecordingObject,
+};
+use crate::room::{CreateRoom, GetRooms, JoinLink, Room, RoomPrivacy, UpdateRoom};
+use crate::{Client, Result, RoomPropertiesBuilder};
+
+/// `Send` on every target except `wasm32`, where the futures returned by `reqwest`
+/// are not `Send`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// `Send` on every target except `wasm32`, where the futures returned by `reqwest`
+/// are not `Send`.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// The requests which can be made to the `Daily` API.
+///
+/// [Client] is the implementation making real requests. Code written
+/// against `impl DailyApi` can be given a mock implementation in its own tests instead.
+///
+/// Requests made with builders, like [CreateRoom], have a
+/// method here taking the builder, equivalent to calling its `send`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dailyco::{Client, DailyApi, Result};
+/// async fn cleanup(api: &impl DailyApi, rooms: &[&str]) -> Result<()> {
+///     for room in rooms {
+///         api.delete_room_if_exists(room).await?;
+///     }
+///     Ok(())
+/// }
+/// # async fn run() -> Result<()> {
+/// let client = Client::new("test-api-key")?;
+/// cleanup(&client, &["room-1", "room-2"]).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait DailyApi {
+    /// See [Client::health_check](crate::Client::health_check).
+    fn health_check(&self) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// See [Client::get_room](crate::Client::get_room).
+    fn get_room(&self, room_name: &str) -> impl Future<Output = Result<Room>> + MaybeSend;
+
+    /// See [Client::get_room_if_changed](crate::Client::get_room_if_changed).
+    fn get_room_if_changed(
+        &self,
+        room_name: &str,
+        etag: Option<&str>,
+    ) -> impl Future<Output = Result<Option<(Room, Option<String>)>>> + MaybeSend;
+
+    /// See [Client::try_get_room](crate::Client::try_get_room).
+    fn try_get_room(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Option<Room>>> + MaybeSend;
+
+    /// See [Client::room_exists](crate::Client::room_exists).
+    fn room_exists(&self, room_name: &str) -> impl Future<Output = Result<bool>> + MaybeSend;
+
+    /// See [Client::get_rooms](crate::Client::get_rooms).
+    fn get_rooms(&self) -> impl Future<Output = Result<Vec<Room>>> + MaybeSend;
+
+    /// See [Client::get_rooms_by_name](crate::Client::get_rooms_by_name).
+    fn get_rooms_by_name(
+        &self,
+        names: &[&str],
+        concurrency: usize,
+    ) -> impl Future<Output = Vec<Result<Room>>> + MaybeSend;
+
+    /// See [Client::count_rooms](crate::Client::count_rooms).
+    fn count_rooms(&self) -> impl Future<Output = Result<usize>> + MaybeSend;
+
+    /// See [Client::get_room_presence](crate::Client::get_room_presence).
+    fn get_room_presence(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Vec<PresenceParticipant>>> + MaybeSend;
+
+    /// See [Client::list_sessions](crate::Client::list_sessions).
+    fn list_sessions(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Vec<Session>>> + MaybeSend;
+
+    /// See [Client::room_remaining_capacity](crate::Client::room_remaining_capacity).
+    fn room_remaining_capacity(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Option<usize>>> + MaybeSend;
+
+    /// See [GetRooms::send](crate::room::GetRooms::send).
+    fn get_rooms_page(
+        &self,
+        request: &GetRooms,
+    ) -> impl Future<Output = Result<Page<Room>>> + MaybeSend;
+
+    /// See [CreateRoom::send](crate::room::CreateRoom::send).
+    fn create_room(&self, room: &CreateRoom) -> impl Future<Output = Result<Room>> + MaybeSend;
+
+    /// See [Client::create_room_with_token](crate::Client::create_room_with_token).
+    fn create_room_with_token(
+        &self,
+        room: &CreateRoom,
+        token: &CreateMeetingToken,
+    ) -> impl Future<Output = Result<JoinLink>> + MaybeSend;
+
+    /// See [Client::create_ephemeral_room](crate::Client::create_ephemeral_room).
+    fn create_ephemeral_room(
+        &self,
+        ttl: Duration,
+        properties: RoomPropertiesBuilder,
+    ) -> impl Future<Output = Result<JoinLink>> + MaybeSend;
+
+    /// See [UpdateRoom::send](crate::room::UpdateRoom::send).
+    fn update_room(
+        &self,
+        room_name: &str,
+        update: &UpdateRoom,
+    ) -> impl Future<Output = Result<Room>> + MaybeSend;
+
+    /// See [Client::set_room_privacy](crate::Client::set_room_privacy).
+    fn set_room_privacy(
+        &self,
+        room_name: &str,
+        privacy: RoomPrivacy,
+    ) -> impl Future<Output = Result<Room>> + MaybeSend;
+
+    /// See [Client::delete_room](crate::Client::delete_room).
+    fn delete_room(&self, room_name: &str) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// See [Client::delete_room_if_exists](crate::Client::delete_room_if_exists).
+    fn delete_room_if_exists(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<bool>> + MaybeSend;
+
+    /// See [Client::delete_rooms](crate::Client::delete_rooms).
+    fn delete_rooms(
+        &self,
+        names: &[&str],
+        concurrency: usize,
+        missing_ok: bool,
+    ) -> impl Future<Output = Vec<Result<()>>> + MaybeSend;
+
+    /// See [Client::update_participant](crate::Client::update_participant).
+    fn update_participant(
+        &self,
+        room_name: &str,
+        session_id: &str,
+        permissions: &TokenPermissions,
+    ) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// See [CreateMeetingToken::send](crate::meeting_token::CreateMeetingToken::send).
+    fn create_meeting_token(
+        &self,
+        token: &CreateMeetingToken,
+    ) -> impl Future<Output = Result<MeetingTokenString>> + MaybeSend;
+
+    /// See [Client::get_meeting_token](crate::Client::get_meeting_token).
+    fn get_meeting_token(
+        &self,
+        token: &str,
+    ) -> impl Future<Output = Result<MeetingToken>> + MaybeSend;
+
+    /// See [Client::validate_tokens](crate::Client::validate_tokens).
+    fn validate_tokens(
+        &self,
+        tokens: &[&str],
+        concurrency: usize,
+    ) -> impl Future<Output = Vec<Result<MeetingToken>>> + MaybeSend;
+
+    /// See [ListRecordings::send](crate::recording::ListRecordings::send).
+    fn list_recordings(
+        &self,
+        list: &ListRecordings,
+    ) -> impl Future<Output = Result<ListedRecordings>> + MaybeSend;
+
+    /// See [Client::get_meeting_logs](crate::Client::get_meeting_logs).
+    fn get_meeting_logs(
+        &self,
+        meeting_session_id: Uuid,
+    ) -> impl Future<Output = Result<MeetingLogs>> + MaybeSend;
+
+    /// See [Client::get_recording](crate::Client::get_recording).
+    fn get_recording(
+        &self,
+        id: RecordingId,
+    ) -> impl Future<Output = Result<RecordingObject>> + MaybeSend;
+
+    /// See [Client::get_recordings_for_room](crate::Client::get_recordings_for_room).
+    fn get_recordings_for_room(
+        &self,
+        room_name: &str,
+        max: Option<usize>,
+    ) -> impl Future<Output = Result<Vec<RecordingObject>>> + MaybeSend;
+
+    /// See [GetRecordingAccessLink::send](crate::recording::GetRecordingAccessLink::send).
+    fn get_recording_access_link(
+        &self,
+        request: &GetRecordingAccessLink,
+        id: RecordingId,
+    ) -> impl Future<Output = Result<RecordingAccessLink>> + MaybeSend;
+
+    /// See [Client::download_recording](crate::Client::download_recording).
+    fn download_recording(
+        &self,
+        id: RecordingId,
+    ) -> impl Future<Output = Result<Bytes>> + MaybeSend;
+
+    /// See [Client::delete_recording](crate::Client::delete_recording).
+    fn delete_recording(&self, id: RecordingId) -> impl Future<Output = Result<()>> + MaybeSend;
+}
+
+impl DailyApi for Client {
+    fn health_check(&self) -> impl Future<Output = Result<()>> + MaybeSend {
+        Client::health_check(self)
+    }
+
+    fn get_room(&self, room_name: &str) -> impl Future<Output = Result<Room>> + MaybeSend {
+        Client::get_room(self, room_name)
+    }
+
+    fn get_room_if_changed(
+        &self,
+        room_name: &str,
+        etag: Option<&str>,
+    ) -> impl Future<Output = Result<Option<(Room, Option<String>)>>> + MaybeSend {
+        Client::get_room_if_changed(self, room_name, etag)
+    }
+
+    fn try_get_room(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Option<Room>>> + MaybeSend {
+        Client::try_get_room(self, room_name)
+    }
+
+    fn room_exists(&self, room_name: &str) -> impl Future<Output = Result<bool>> + MaybeSend {
+        Client::room_exists(self, room_name)
+    }
+
+    fn get_rooms(&self) -> impl Future<Output = Result<Vec<Room>>> + MaybeSend {
+        Client::get_rooms(self)
+    }
+
+    fn get_rooms_by_name(
+        &self,
+        names: &[&str],
+        concurrency: usize,
+    ) -> impl Future<Output = Vec<Result<Room>>> + MaybeSend {
+        Client::get_rooms_by_name(self, names, concurrency)
+    }
+
+    fn count_rooms(&self) -> impl Future<Output = Result<usize>> + MaybeSend {
+        Client::count_rooms(self)
+    }
+
+    fn get_room_presence(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Vec<PresenceParticipant>>> + MaybeSend {
+        Client::get_room_presence(self, room_name)
+    }
+
+    fn list_sessions(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Vec<Session>>> + MaybeSend {
+        Client::list_sessions(self, room_name)
+    }
+
+    fn room_remaining_capacity(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<Option<usize>>> + MaybeSend {
+        Client::room_remaining_capacity(self, room_name)
+    }
+
+    fn get_rooms_page(
+        &self,
+        request: &GetRooms,
+    ) -> impl Future<Output = Result<Page<Room>>> + MaybeSend {
+        request.send(self)
+    }
+
+    fn create_room(&self, room: &CreateRoom) -> impl Future<Output = Result<Room>> + MaybeSend {
+        room.send(self)
+    }
+
+    fn create_room_with_token(
+        &self,
+        room: &CreateRoom,
+        token: &CreateMeetingToken,
+    ) -> impl Future<Output = Result<JoinLink>> + MaybeSend {
+        Client::create_room_with_token(self, room, token)
+    }
+
+    fn create_ephemeral_room(
+        &self,
+        ttl: Duration,
+        properties: RoomPropertiesBuilder,
+    ) -> impl Future<Output = Result<JoinLink>> + MaybeSend {
+        Client::create_ephemeral_room(self, ttl, properties)
+    }
+
+    fn update_room(
+        &self,
+        room_name: &str,
+        update: &UpdateRoom,
+    ) -> impl Future<Output = Result<Room>> + MaybeSend {
+        update.send(room_name, self)
+    }
+
+    fn set_room_privacy(
+        &self,
+        room_name: &str,
+        privacy: RoomPrivacy,
+    ) -> impl Future<Output = Result<Room>> + MaybeSend {
+        Client::set_room_privacy(self, room_name, privacy)
+    }
+
+    fn delete_room(&self, room_name: &str) -> impl Future<Output = Result<()>> + MaybeSend {
+        Client::delete_room(self, room_name)
+    }
+
+    fn delete_room_if_exists(
+        &self,
+        room_name: &str,
+    ) -> impl Future<Output = Result<bool>> + MaybeSend {
+        Client::delete_room_if_exists(self, room_name)
+    }
+
+    fn delete_rooms(
+        &self,
+        names: &[&str],
+        concurrency: usize,
+        missing_ok: bool,
+    ) -> impl Future<Output = Vec<Result<()>>> + MaybeSend {
+        Client::delete_rooms(self, names, concurrency, missing_ok)
+    }
+
+    fn update_participant(
+        &self,
+        room_name: &str,
+        session_id: &str,
+        permissions: &TokenPermissions,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        Client::update_participant(self, room_name, session_id, permissions)
+    }
+
+    fn create_meeting_token(
+        &self,
+        token: &CreateMeetingToken,
+    ) -> impl Future<Output = Result<MeetingTokenString>> + MaybeSend {
+        token.send(self)
+    }
+
+    fn get_meeting_token(
+        &self,
+        token: &str,
+    ) -> impl Future<Output = Result<MeetingToken>> + MaybeSend {
+        Client::get_meeting_token(self, token)
+    }
+
+    fn validate_tokens(
+        &self,
+        tokens: &[&str],
+        concurrency: usize,
+    ) -> impl Future<Output = Vec<Result<MeetingToken>>> + MaybeSend {
+        Client::validate_tokens(self, tokens, concurrency)
+    }
+
+    fn list_recordings(
+        &self,
+        list: &ListRecordings,
+    ) -> impl Future<Output = Result<ListedRecordings>> + MaybeSend {
+        list.send(self)
+    }
+
+    fn get_meeting_logs(
+        &self,
+        meeting_session_id: Uuid,
+    ) -> impl Future<Output = Result<MeetingLogs>> + MaybeSend {
+        Client::get_meeting_logs(self, meeting_session_id)
+    }
+
+    fn get_recording(
+        &self,
+        id: RecordingId,
+    ) -> impl Future<Output = Result<RecordingObject>> + MaybeSend {
+        Client::get_recording(self, id)
+    }
+
+    fn get_recordings_for_room(
+        &self,
+        room_name: &str,
+        max: Option<usize>,
+    ) -> impl Future<Output = Result<Vec<RecordingObject>>> + MaybeSend {
+        Client::get_recordings_for_room(self, room_name, max)
+    }
+
+    fn get_recording_access_link(
+        &self,
+        request: &GetRecordingAccessLink,
+        id: RecordingId,
+    ) -> impl Future<Output = Result<RecordingAccessLink>> + MaybeSend {
+        request.send(self, id)
+    }
+
+    fn download_recording(
+        &self,
+        id: RecordingId,
+    ) -> impl Future<Output = Result<Bytes>> + MaybeSend {
+        Client::download_recording(self, id)
+    }
+
+    fn delete_recording(&self, id: RecordingId) -> impl Future<Output = Result<()>> + MaybeSend {
+        Client::delete_recording(self, id)
+    }
+}
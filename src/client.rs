@@ -1,23 +1,77 @@
+use std::collections::BTreeMap;
 use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Response, Url};
+use bytes::Bytes;
+use futures_timer::Delay;
+use futures_util::future::{self, Either};
+use futures_util::pin_mut;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+};
+use reqwest::{IntoUrl, Method, RequestBuilder, Response, StatusCode, Url};
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::meeting_token::MeetingToken;
-use crate::recording::RecordingObject;
-use crate::room::Room;
+use crate::logs::MeetingLogs;
+use crate::meeting_token::{CreateMeetingToken, MeetingToken, TokenPermissions};
+use crate::pagination::DEFAULT_LIST_LIMIT;
+use crate::presence::{PresenceParticipant, Session};
+use crate::recording::{GetRecordingAccessLink, ListRecordings, RecordingId, RecordingObject};
+use crate::room::{CreateRoom, GetRooms, JoinLink, Room, RoomPrivacy, UpdateRoom};
+use crate::room_properties::RoomPropertiesBuilder;
+use crate::utils::timestamp_in;
 use crate::{Error, Result};
 
 const BASE_URL: &str = "https://api.daily.co/v1/";
+const AUTH_SCHEME: &str = "Bearer ";
+const DEFAULT_USER_AGENT: &str = concat!("dailyco-rs/", env!("CARGO_PKG_VERSION"));
 
 /// A `Client` to make `Daily` API requests with.
-#[derive(Debug, Clone)]
+///
+/// Cloning a `Client` is cheap: clones share the same connection pool and
+/// configuration, so there is no need to wrap it in an `Arc` to share it between
+/// tasks or threads.
+///
+/// The API key is redacted from the `Debug` output.
+#[derive(Clone)]
 pub struct Client {
     pub(crate) client: reqwest::Client,
-    pub(crate) base_url: Url,
+    pub(crate) base_url: Arc<Url>,
+    auth: HeaderValue,
+    /// Where the id of each response is kept, for [Client::with_request_id].
+    request_id: Option<Arc<Mutex<Option<String>>>>,
+    headers: Arc<HeaderMap>,
+    on_request_body: Option<RequestBodyHook>,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `auth` is left out rather than trusting its `Debug` to stay redacted
+        f.debug_struct("Client")
+            .field("client", &self.client)
+            .field("base_url", &self.base_url)
+            .field("auth", &"Sensitive")
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("on_request_body", &self.on_request_body)
+            .finish()
+    }
+}
+
+type RequestBodyFn = dyn Fn(&Method, &Url, &str) + Send + Sync;
+
+/// A callback given the method, URL and JSON body of each request sent with a body.
+#[derive(Clone)]
+struct RequestBodyHook(Arc<RequestBodyFn>);
+
+impl fmt::Debug for RequestBodyHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestBodyHook")
+    }
 }
 
 impl Client {
@@ -38,9 +92,7 @@ impl Client {
     /// # }
     /// ```
     pub fn new<T: fmt::Display>(key: T) -> Result<Self> {
-        // We should be guaranteed this parsing will not fail
-        let base_url = Url::parse(BASE_URL).unwrap();
-        Self::with_endpoint(key, base_url)
+        Self::builder(key).build()
     }
 
     /// Creates a [Client](crate::Client) with a custom endpoint. This is primarily
@@ -51,31 +103,388 @@ impl Client {
     /// ```
     /// # use dailyco::{Client, Result};
     /// # fn main_fn() -> Result<Client> {
-    /// let mock_server_addr = "http://localhost:8080";
-    /// let client = Client::with_endpoint(
-    ///     "test-api-key",
-    ///     reqwest::Url::parse(mock_server_addr).unwrap(),
-    /// )?;
+    /// let mock_server_addr = reqwest::Url::parse("http://localhost:8080").unwrap();
+    /// let client = Client::with_endpoint("test-api-key", mock_server_addr)?;
     /// Ok(client)
     /// # }
     /// ```
     pub fn with_endpoint<T: fmt::Display>(key: T, endpoint: Url) -> Result<Self> {
-        let mut header_val = HeaderValue::try_from(format!("Bearer {}", key))
-            .map_err(|_| Error::BadAPIKey("API key must include only ASCII characters"))?;
-        header_val.set_sensitive(true);
+        Self::builder(key).endpoint(endpoint).build()
+    }
+
+    /// Creates a [Client](crate::Client) with a custom endpoint given as a string, e.g.
+    /// read from an environment variable. See [with_endpoint](Self::with_endpoint).
+    ///
+    /// # Errors
+    ///
+    /// [`Error::BadEndpoint`] if `endpoint` isn't a valid URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::{Client, Result};
+    /// # fn main_fn() -> Result<Client> {
+    /// let client = Client::with_endpoint_str("test-api-key", "http://localhost:8080")?;
+    /// Ok(client)
+    /// # }
+    /// ```
+    pub fn with_endpoint_str<T: fmt::Display>(key: T, endpoint: &str) -> Result<Self> {
+        let parsed = Url::parse(endpoint).map_err(|source| Error::BadEndpoint {
+            endpoint: endpoint.to_string(),
+            source,
+        })?;
+        Self::with_endpoint(key, parsed)
+    }
+
+    /// Start a [ClientBuilder](crate::ClientBuilder) to configure the underlying HTTP
+    /// client, e.g. to go through a proxy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::{Client, Result};
+    /// # fn main_fn() -> Result<Client> {
+    /// let client = Client::builder("test-api-key")
+    ///     .proxy(reqwest::Proxy::https("http://proxy.internal:3128")?)
+    ///     .build()?;
+    /// Ok(client)
+    /// # }
+    /// ```
+    pub fn builder<T: fmt::Display>(key: T) -> ClientBuilder {
+        // We should be guaranteed this parsing will not fail
+        let endpoint = Url::parse(BASE_URL).unwrap();
+        ClientBuilder {
+            key: key.to_string(),
+            endpoint,
+            http: reqwest::Client::builder().user_agent(DEFAULT_USER_AGENT),
+            headers: Vec::new(),
+            on_request_body: None,
+        }
+    }
+
+    /// The endpoint requests are sent to, `https://api.daily.co/v1/` unless configured
+    /// otherwise.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Whether a non-empty API key was configured. The key itself can't be read back
+    /// from a `Client`, and is redacted from its `Debug` output.
+    pub fn has_api_key(&self) -> bool {
+        self.auth.as_bytes().len() > AUTH_SCHEME.len()
+    }
 
+    /// A clone of this client which also sends the headers in `options`, replacing
+    /// any default header of the same name. The clone shares this client's connection
+    /// pool, so making one per request is cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, RequestOptions, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let room = client
+    ///     .with_options(&RequestOptions::new().header("x-tenant-id", "tenant-42")?)
+    ///     .get_room("room-which-exists")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: &RequestOptions) -> Client {
+        let mut client = self.clone();
+        let headers = Arc::make_mut(&mut client.headers);
+        for (name, value) in &options.headers {
+            headers.insert(name, value.clone());
+        }
+        client
+    }
+
+    // The API key is attached per request rather than as a default header, so
+    // that requests to other hosts (e.g. recording downloads) never carry it.
+    pub(crate) fn request(&self, method: Method, url: impl IntoUrl) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .headers(HeaderMap::clone(&self.headers))
+            .header(AUTHORIZATION, self.auth.clone())
+    }
+
+    pub(crate) fn get(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.request(Method::GET, url)
+    }
+
+    pub(crate) fn post(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.request(Method::POST, url)
+    }
+
+    pub(crate) fn delete(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.request(Method::DELETE, url)
+    }
+
+    /// Run `f` with a clone of this client, returning its result together with the id
+    /// `Daily` assigned to the request it made, e.g. to quote to `Daily` support about a
+    /// request which succeeded. If `f` makes several requests, this is the id of the
+    /// last one.
+    ///
+    /// Failed requests already carry their id, see [Error::request_id].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let room = client
+    ///     .with_request_id(|client| async move { client.get_room("a-room").await })
+    ///     .await?;
+    /// println!("{} was fetched by request {:?}", room.value.name, room.request_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_request_id<T, F, Fut>(&self, f: F) -> Result<WithRequestId<T>>
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let slot = Arc::new(Mutex::new(None));
+        let client = Client {
+            request_id: Some(Arc::clone(&slot)),
+            ..self.clone()
+        };
+        let value = f(client).await?;
+        let request_id = slot.lock().unwrap_or_else(PoisonError::into_inner).take();
+        Ok(WithRequestId { value, request_id })
+    }
+
+    /// A POST request with `body` serialized as its JSON body, passed to the
+    /// [on_request_body](ClientBuilder::on_request_body) hook if one is set.
+    pub(crate) fn post_json(&self, url: Url, body: &impl Serialize) -> RequestBuilder {
+        let body = serde_json::to_string(body).expect("Request bodies are always serializable");
+        if let Some(RequestBodyHook(hook)) = &self.on_request_body {
+            hook(&Method::POST, &url, &body);
+        }
+        self.post(url)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .body(body)
+    }
+
+    /// Send a request built by this client, keeping the id of its response for
+    /// [with_request_id](Self::with_request_id) and counting its outcome with the
+    /// `metrics` feature enabled.
+    pub(crate) async fn execute(&self, request: RequestBuilder) -> Result<Response> {
+        let resp = self.send(request).await?;
+        if let Some(slot) = &self.request_id {
+            *slot.lock().unwrap_or_else(PoisonError::into_inner) = crate::error::request_id(&resp);
+        }
+        Ok(resp)
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        #[cfg(feature = "metrics")]
+        {
+            let request = request.build()?;
+            let (method, url) = (request.method().clone(), request.url().clone());
+            let res = self.client.execute(request).await;
+            crate::instrumentation::record(&self.base_url, &method, &url, &res);
+            Ok(res?)
+        }
+        #[cfg(not(feature = "metrics"))]
+        Ok(request.send().await?)
+    }
+}
+
+/// A builder for a [Client](crate::Client), started with [Client::builder](crate::Client::builder).
+///
+/// Options which configure the underlying `reqwest` client are forwarded to
+/// [reqwest::ClientBuilder] before it is built.
+#[must_use]
+pub struct ClientBuilder {
+    key: String,
+    endpoint: Url,
+    http: reqwest::ClientBuilder,
+    headers: Vec<(String, String)>,
+    on_request_body: Option<RequestBodyHook>,
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("key", &"Sensitive")
+            .field("endpoint", &self.endpoint)
+            .field("http", &self.http)
+            .field(
+                "headers",
+                &self
+                    .headers
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("on_request_body", &self.on_request_body)
+            .finish()
+    }
+}
+
+impl ClientBuilder {
+    /// Send requests to this endpoint instead of the `Daily` API, see
+    /// [Client::with_endpoint](crate::Client::with_endpoint).
+    pub fn endpoint(mut self, endpoint: Url) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Identify requests with this `User-Agent`, instead of the default
+    /// `dailyco-rs/<version>`. A value which isn't a valid header makes
+    /// [build](Self::build) fail.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.http = self.http.user_agent(user_agent);
+        self
+    }
+
+    /// Send this header with every request to `Daily`, e.g. to opt in to a beta
+    /// feature. Headers can be added or replaced for specific requests with
+    /// [Client::with_options](crate::Client::with_options).
+    ///
+    /// Unlike headers configured on the underlying `reqwest` client, these aren't sent
+    /// with requests to other hosts, like recording downloads. A header which isn't
+    /// valid, or is `Authorization`, which is always set from the API key, makes
+    /// [build](Self::build) fail.
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Send requests through this proxy, in addition to any configured through
+    /// the environment (e.g. `HTTPS_PROXY`).
+    ///
+    /// Not available on `wasm32` targets, where the browser handles proxying.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http = self.http.proxy(proxy);
+        self
+    }
+
+    /// Trust this additional root certificate, e.g. a corporate CA.
+    ///
+    /// Loading a certificate with [reqwest::Certificate::from_pem] or
+    /// [reqwest::Certificate::from_der] requires one of this crate's TLS features:
+    /// the default `reqwest/default-tls`, `rustls-tls-webpki-roots` or
+    /// `rustls-tls-native-roots`.
+    ///
+    /// Not available on `wasm32` targets, where the browser handles TLS.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.http = self.http.add_root_certificate(certificate);
+        self
+    }
+
+    /// Call `hook` with the method, URL and JSON body of every request sent with a
+    /// body, e.g. creating a room, before it is sent. Useful to see exactly what was
+    /// sent when `Daily` rejects a request.
+    ///
+    /// The API key is sent as a header, so never appears in the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::{Client, Result};
+    /// # fn run() -> Result<Client> {
+    /// let client = Client::builder("test-api-key")
+    ///     .on_request_body(|method, url, body| eprintln!("{method} {url}: {body}"))
+    ///     .build()?;
+    /// # Ok(client)
+    /// # }
+    /// ```
+    pub fn on_request_body(
+        mut self,
+        hook: impl Fn(&Method, &Url, &str) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_request_body = Some(RequestBodyHook(Arc::new(hook)));
+        self
+    }
+
+    /// Build the [Client](crate::Client).
+    ///
+    /// # Errors
+    ///
+    /// If the API key does not contain only ASCII characters, or the underlying
+    /// `reqwest` client can't be built (e.g. the TLS backend fails to initialize),
+    /// an error variant will be returned.
+    pub fn build(self) -> Result<Client> {
+        let mut auth = HeaderValue::try_from(format!("{AUTH_SCHEME}{}", self.key))
+            .map_err(|_| Error::BadAPIKey("API key must include only ASCII characters"))?;
+        auth.set_sensitive(true);
         let mut headers = HeaderMap::new();
-        headers.insert(reqwest::header::AUTHORIZATION, header_val);
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()?;
-        Ok(Self {
+        for (name, value) in &self.headers {
+            let (name, value) = parse_header(name, value)?;
+            headers.insert(name, value);
+        }
+
+        let client = self.http.build()?;
+        Ok(Client {
             client,
-            base_url: endpoint,
+            base_url: Arc::new(self.endpoint),
+            auth,
+            request_id: None,
+            headers: Arc::new(headers),
+            on_request_body: self.on_request_body,
         })
     }
 }
 
+/// The result of a request together with the id `Daily` assigned to it, as returned
+/// by [Client::with_request_id](crate::Client::with_request_id).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithRequestId<T> {
+    /// The result of the request.
+    pub value: T,
+    /// The id `Daily` assigned to the request, if it returned one.
+    pub request_id: Option<String>,
+}
+
+/// Options for specific requests, applied with
+/// [Client::with_options](crate::Client::with_options).
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct RequestOptions {
+    headers: HeaderMap,
+}
+
+impl RequestOptions {
+    /// Start new `RequestOptions`, which change nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send this header, replacing a [default header](ClientBuilder::default_header)
+    /// of the same name.
+    ///
+    /// # Errors
+    ///
+    /// `crate::Error::InvalidConfig` if the header isn't valid, or is `Authorization`,
+    /// which is always set from the API key.
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self> {
+        let (name, value) = parse_header(name, value)?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+}
+
+/// Parse a header given as strings, rejecting `Authorization` so that it is only ever
+/// set from the API key.
+fn parse_header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue)> {
+    let parsed = HeaderName::try_from(name)
+        .map_err(|_| Error::invalid_config(format!("{name:?} is not a valid header name")))?;
+    if parsed == AUTHORIZATION {
+        return Err(Error::invalid_config(
+            "the authorization header is always set from the API key",
+        ));
+    }
+    let value = HeaderValue::try_from(value)
+        .map_err(|_| Error::invalid_config(format!("the value of {name} is not a valid header")))?;
+    Ok((parsed, value))
+}
+
 impl Client {
     /// Retrieve the `Daily` room corresponding to this name.
     ///
@@ -94,13 +503,134 @@ impl Client {
     /// ```
     pub async fn get_room(&self, room_name: &str) -> Result<Room> {
         let url = self.get_room_url_with_name(room_name);
-        let resp = self.client.get(url).send().await?;
+        let resp = self.execute(self.get(url)).await?;
 
         parse_dailyco_response(resp).await
     }
 
+    /// Retrieve the `Daily` room corresponding to this name if it changed since it
+    /// was fetched with `etag`, returning it along with its new `ETag`. Returns `None`
+    /// if it is unchanged, without `Daily` sending the room again.
+    ///
+    /// Pass `None` as `etag` to always fetch the room, e.g. the first time. If
+    /// `Daily` doesn't send an `ETag` for the room, `None` is returned in its place,
+    /// and passing it back always refetches the room, like [get_room](Self::get_room).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let mut etag = None;
+    /// loop {
+    ///     let changed = client.get_room_if_changed("a-room", etag.as_deref()).await?;
+    ///     if let Some((room, new_etag)) = changed {
+    ///         println!("{} changed", room.name);
+    ///         etag = new_etag;
+    ///     }
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_room_if_changed(
+        &self,
+        room_name: &str,
+        etag: Option<&str>,
+    ) -> Result<Option<(Room, Option<String>)>> {
+        let url = self.get_room_url_with_name(room_name);
+        let mut request = self.get(url);
+        if let Some(etag) = etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let resp = self.execute(request).await?;
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+
+        let etag = resp
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        let room = parse_dailyco_response(resp).await?;
+        Ok(Some((room, etag)))
+    }
+
+    /// Change only the privacy of the `Daily` room with this name, leaving its
+    /// properties as they are, and return the updated room.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::room::{Room, RoomPrivacy};
+    /// # async fn run() -> Result<Room> {
+    /// let client = Client::new("test-api-key")?;
+    /// let room = client.set_room_privacy("a-room", RoomPrivacy::Public).await?;
+    /// # Ok(room)
+    /// # }
+    /// ```
+    pub async fn set_room_privacy(&self, room_name: &str, privacy: RoomPrivacy) -> Result<Room> {
+        UpdateRoom::new()
+            .privacy(privacy)
+            .send(room_name, self)
+            .await
+    }
+
+    /// Retrieve the `Daily` room corresponding to this name, or `None` if there
+    /// is no such room.
+    ///
+    /// Only a not found response is treated as the room not existing, any other
+    /// error is returned as is. Use [get_room](Self::get_room) to treat a missing
+    /// room as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// if let Some(room) = client.try_get_room("maybe-a-room").await? {
+    ///     println!("{} is at {}", room.name, room.url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn try_get_room(&self, room_name: &str) -> Result<Option<Room>> {
+        match self.get_room(room_name).await {
+            Ok(room) => Ok(Some(room)),
+            Err(err) if err.is_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Check whether a `Daily` room with this name exists.
+    ///
+    /// Only a not found response is treated as the room not existing, any other
+    /// error (e.g. an invalid API key or rate limiting) is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<bool> {
+    /// let client = Client::new("test-api-key")?;
+    /// let exists = client.room_exists("maybe-a-room").await?;
+    /// # Ok(exists)
+    /// # }
+    /// ```
+    pub async fn room_exists(&self, room_name: &str) -> Result<bool> {
+        Ok(self.try_get_room(room_name).await?.is_some())
+    }
+
     /// Validate and retrieve configuration information for the provided meeting token.
     ///
+    /// A token which is past its `exp` is rejected as
+    /// [`DailyCoErrorKind::TokenExpired`](crate::DailyCoErrorKind::TokenExpired), rather
+    /// than the `InvalidRequestError` of a malformed token.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -114,7 +644,7 @@ impl Client {
     ///     .room_name("room-which-exists")
     ///     .send(&client)
     ///     .await?;
-    /// let validated = client.get_meeting_token(&token).await?;
+    /// let validated = client.get_meeting_token(token.as_str()).await?;
     /// assert_eq!(validated.room_name, Some("room-which-exists".to_string()));
     /// # Ok(())
     /// # }
@@ -126,15 +656,167 @@ impl Client {
             .unwrap()
             .join(token)
             .unwrap();
-        let resp = self.client.get(url).send().await?;
+        let resp = self.execute(self.get(url)).await?;
 
         parse_dailyco_response(resp).await
     }
 
+    /// Validate many meeting tokens with `Daily`, making up to `concurrency` requests
+    /// at a time.
+    ///
+    /// `Daily` has no endpoint to validate tokens in bulk, so this makes one request
+    /// per token, each counting towards the account's rate limit. A burst of tokens,
+    /// e.g. from clients reconnecting all at once, can run into it: keep `concurrency`
+    /// low, and for self-signed tokens prefer `validate_tokens_with_keys`, with the
+    /// `self-signed-tokens` feature enabled, which doesn't need a request for them.
+    ///
+    /// One result is returned per token, in the same order as `tokens`, as from
+    /// [get_meeting_token](Self::get_meeting_token).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run(tokens: &[&str]) -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// for (token, validated) in tokens.iter().zip(client.validate_tokens(tokens, 4).await) {
+    ///     if validated.is_err() {
+    ///         println!("rejecting {token}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_tokens(
+        &self,
+        tokens: &[&str],
+        concurrency: usize,
+    ) -> Vec<Result<MeetingToken>> {
+        // Collected up front for the same reason as in `delete_rooms`
+        let fetches: Vec<_> = tokens
+            .iter()
+            .map(|token| self.get_meeting_token(token))
+            .collect();
+        stream::iter(fetches)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Like [validate_tokens](Self::validate_tokens), but tokens signed with one of
+    /// `secret_keys` are decoded locally with
+    /// [decode_with_keys](crate::meeting_token::decode_with_keys), without a request.
+    /// Only tokens none of the keys verify, like ones `Daily` created, are sent to
+    /// `Daily`.
+    ///
+    /// This requires the optional `self-signed-tokens` feature enabled.
+    #[cfg(feature = "self-signed-tokens")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+    pub async fn validate_tokens_with_keys(
+        &self,
+        tokens: &[&str],
+        secret_keys: &[&str],
+        concurrency: usize,
+    ) -> Vec<Result<MeetingToken>> {
+        let validations: Vec<_> = tokens
+            .iter()
+            .map(|token| async move {
+                match crate::meeting_token::decode_with_keys(token, secret_keys) {
+                    Err(Error::MeetingTokenKeyMismatch) => self.get_meeting_token(token).await,
+                    decoded => decoded,
+                }
+            })
+            .collect();
+        stream::iter(validations)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Create a room, and a meeting token for it, returning a [JoinLink] with
+    /// the URL which joins the room using the token.
+    ///
+    /// The token's `room_name` is set to the created room, any other properties are
+    /// taken from `token`. If creating the token fails the room is left in place.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::meeting_token::CreateMeetingToken;
+    /// # use dailyco::room::{CreateRoom, RoomPrivacy};
+    /// # async fn run() -> Result<String> {
+    /// let client = Client::new("test-api-key")?;
+    /// let link = client
+    ///     .create_room_with_token(
+    ///         CreateRoom::new().privacy(RoomPrivacy::Private),
+    ///         &CreateMeetingToken::new().is_owner(true).user_name("Host"),
+    ///     )
+    ///     .await?;
+    /// # Ok(link.url)
+    /// # }
+    /// ```
+    pub async fn create_room_with_token(
+        &self,
+        room: &CreateRoom<'_>,
+        token: &CreateMeetingToken<'_>,
+    ) -> Result<JoinLink> {
+        let room = room.send(self).await?;
+        let token = token.room_name(&room.name).send(self).await?;
+        let url = room.join_url_with_token(token.as_str());
+        Ok(JoinLink { room, token, url })
+    }
+
+    /// Create a private room which expires `ttl` from now, and an owner meeting token
+    /// for it which expires along with it.
+    ///
+    /// The room's `exp` is set from `ttl`, and `eject_at_room_exp` so that nobody is
+    /// left in it, overriding either if set in `properties`. `Daily` deletes the room
+    /// some time after it expires. As with
+    /// [create_room_with_token](Self::create_room_with_token), if creating the token
+    /// fails the room is left in place.
+    ///
+    /// A `ttl` of less than a second is rejected with `crate::Error::InvalidConfig`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use dailyco::{Client, Result, RoomPropertiesBuilder};
+    /// # async fn run() -> Result<String> {
+    /// let client = Client::new("test-api-key")?;
+    /// let link = client
+    ///     .create_ephemeral_room(
+    ///         Duration::from_secs(30 * 60),
+    ///         RoomPropertiesBuilder::new().max_participants(2),
+    ///     )
+    ///     .await?;
+    /// # Ok(link.url)
+    /// # }
+    /// ```
+    pub async fn create_ephemeral_room(
+        &self,
+        ttl: Duration,
+        properties: RoomPropertiesBuilder<'_>,
+    ) -> Result<JoinLink> {
+        if ttl < Duration::from_secs(1) {
+            return Err(Error::invalid_config(
+                "an ephemeral room must last at least a second",
+            ));
+        }
+        let exp = timestamp_in(ttl);
+        let mut room = CreateRoom::new();
+        room.privacy(RoomPrivacy::Private)
+            .properties(properties.exp(exp).eject_at_room_exp(true));
+        let token = CreateMeetingToken::new().is_owner(true).exp(exp);
+        self.create_room_with_token(&room, &token).await
+    }
+
     /// Retrieve all `Daily` rooms for the account.
     ///
-    /// Pagination is currently unimplemented, so queries returning
-    /// more than `100` rooms will return a `crate::Error::RequiresPagination`.
+    /// This fetches a single page, so queries returning more than `100` rooms will
+    /// return a `crate::Error::RequiresPagination`. Use [GetRooms](crate::room::GetRooms)
+    /// to page through them instead.
     ///
     /// # Examples
     ///
@@ -148,38 +830,458 @@ impl Client {
     /// # }
     /// ```
     pub async fn get_rooms(&self) -> Result<Vec<Room>> {
-        #[derive(Debug, Deserialize)]
-        struct GetRoomsResponse {
-            total_count: usize,
-            data: Vec<Room>,
+        let page = GetRooms::new().send(self).await?;
+        if page.total_count() >= DEFAULT_LIST_LIMIT as usize {
+            Err(Error::RequiresPagination)
+        } else {
+            Ok(page.into_data())
         }
+    }
 
-        let url = self.base_url.join("rooms/").unwrap();
-        let resp = self.client.get(url).send().await?;
-        let data: GetRoomsResponse = parse_dailyco_response(resp).await?;
-        if data.total_count >= 100 {
-            Err(Error::RequiresPagination)
+    /// Retrieve the `Daily` rooms with these names, making up to `concurrency` requests
+    /// at a time.
+    ///
+    /// One result is returned per name, in the same order as `names`. A room which
+    /// doesn't exist has the same not found error in its place as from
+    /// [get_room](Self::get_room).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let names = ["room-1", "room-2", "room-3"];
+    /// let rooms = client.get_rooms_by_name(&names, 2).await;
+    /// for (name, room) in names.iter().zip(rooms) {
+    ///     match room {
+    ///         Ok(room) => println!("{name} is at {}", room.url),
+    ///         Err(err) => println!("{name} is unavailable: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_rooms_by_name(&self, names: &[&str], concurrency: usize) -> Vec<Result<Room>> {
+        // Collected up front for the same reason as in `delete_rooms`
+        let fetches: Vec<_> = names.iter().map(|name| self.get_room(name)).collect();
+        stream::iter(fetches)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Count the `Daily` rooms for the account, without retrieving them all.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<usize> {
+    /// let client = Client::new("test-api-key")?;
+    /// let room_count = client.count_rooms().await?;
+    /// # Ok(room_count)
+    /// # }
+    /// ```
+    pub async fn count_rooms(&self) -> Result<usize> {
+        let page = GetRooms::new().limit(1).send(self).await?;
+        Ok(page.total_count())
+    }
+
+    /// Check that `Daily` is reachable and accepts the API key, with a single request
+    /// for one room.
+    ///
+    /// # Errors
+    ///
+    /// The error of the request, which [`Error::is_bad_api_key`] tells apart when `Daily`
+    /// rejected the API key, keeping `Daily`'s error info and request id. Otherwise it is
+    /// e.g. an [`Error::Request`] when `Daily` can't be reached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Error};
+    /// # async fn run() -> dailyco::Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// match client.health_check().await {
+    ///     Ok(()) => println!("ready"),
+    ///     Err(err) if err.is_bad_api_key() => println!("misconfigured: {err}"),
+    ///     Err(err) => println!("Daily unavailable: {err}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> Result<()> {
+        GetRooms::new().limit(1).send(self).await.map(|_| ())
+    }
+
+    /// How many more participants can join the room with this name, or `None` if
+    /// the room doesn't set a `max_participants`.
+    ///
+    /// Combines the room's configuration with the number of participants `Daily`
+    /// currently reports as present in it, which is capped at 0 when the room is
+    /// over capacity.
+    ///
+    /// Presence lags behind participants actually joining and leaving by a few
+    /// seconds, so the capacity is only an estimate: a participant who just joined
+    /// may not be counted yet. Don't rely on it alone to enforce the limit, `Daily`
+    /// will still turn away participants beyond `max_participants`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// if client.room_remaining_capacity("a-room").await? == Some(0) {
+    ///     println!("a-room is full");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn room_remaining_capacity(&self, room_name: &str) -> Result<Option<usize>> {
+        let room = self.get_room(room_name).await?;
+        let Some(max_participants) = room.config.max_participants else {
+            return Ok(None);
+        };
+
+        let present = self.get_room_presence(room_name).await?.len();
+        Ok(Some(max_participants.saturating_sub(present)))
+    }
+
+    /// Retrieve the participants currently in a meeting in the room with this name.
+    ///
+    /// `Daily` filters the presence to the room server-side, so this stays cheap on
+    /// domains with many other participants. A room without an ongoing meeting has no
+    /// participants.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// for participant in client.get_room_presence("a-room").await? {
+    ///     println!("{:?} joined at {}", participant.user_name, participant.join_time);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_room_presence(&self, room_name: &str) -> Result<Vec<PresenceParticipant>> {
+        #[derive(Deserialize)]
+        struct RoomPresenceResponse {
+            data: Vec<PresenceParticipant>,
+        }
+
+        let url = self.get_room_url_with_name(&format!("{room_name}/presence"));
+        let resp = self.execute(self.get(url)).await?;
+        let presence: RoomPresenceResponse = parse_dailyco_response(resp).await?;
+        Ok(presence.data)
+    }
+
+    /// List the sessions of participants in the room with this name, both those in
+    /// the meeting now and those who have left, most recent first. Useful to report
+    /// on who accessed a room.
+    ///
+    /// `Daily` has no endpoint listing sessions as such, so this is composed from two
+    /// requests: the room's [presence](Self::get_room_presence) for active sessions, and
+    /// the participants of the room's meetings from `/meetings` for the rest. Only the
+    /// first page of meetings is read, i.e. the 100 most recent. The `join_time` of an
+    /// active session is worked out from its `duration`, so is only accurate to the
+    /// second.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// for session in client.list_sessions("a-room").await? {
+    ///     println!(
+    ///         "{:?} joined at {} for {}s",
+    ///         session.user_name, session.join_time, session.duration
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_sessions(&self, room_name: &str) -> Result<Vec<Session>> {
+        #[derive(Deserialize)]
+        struct MeetingsResponse {
+            data: Vec<Meeting>,
+        }
+
+        #[derive(Deserialize)]
+        struct Meeting {
+            participants: Vec<MeetingParticipant>,
+        }
+
+        #[derive(Deserialize)]
+        struct MeetingParticipant {
+            participant_id: Uuid,
+            user_id: Option<String>,
+            user_name: Option<String>,
+            join_time: i64,
+            duration: u64,
+        }
+
+        let now = timestamp_in(Duration::ZERO);
+        let mut sessions: Vec<_> = self
+            .get_room_presence(room_name)
+            .await?
+            .into_iter()
+            .map(|participant| Session {
+                session_id: participant.id,
+                user_id: participant.user_id,
+                user_name: participant.user_name,
+                join_time: now.saturating_sub_unsigned(participant.duration),
+                duration: participant.duration,
+                active: true,
+            })
+            .collect();
+
+        // This should not be able to fail
+        let url = self.base_url.join("meetings").unwrap();
+        let request = self.get(url).query(&[("room", room_name)]);
+        let resp = self.execute(request).await?;
+        let meetings: MeetingsResponse = parse_dailyco_response(resp).await?;
+        let past = meetings
+            .data
+            .into_iter()
+            .flat_map(|meeting| meeting.participants)
+            .map(|participant| Session {
+                session_id: participant.participant_id,
+                user_id: participant.user_id,
+                user_name: participant.user_name,
+                join_time: participant.join_time,
+                duration: participant.duration,
+                active: false,
+            });
+        // A participant of an ongoing meeting can be listed there too, but their
+        // presence is more up to date
+        for session in past {
+            if !sessions
+                .iter()
+                .any(|active| active.session_id == session.session_id)
+            {
+                sessions.push(session);
+            }
+        }
+
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.join_time));
+        Ok(sessions)
+    }
+
+    /// Change the permissions of the participant with this session id, during a meeting
+    /// in the room with this name, without issuing them a new meeting token.
+    ///
+    /// # Examples
+    ///
+    /// Stop a participant from sending anything but audio:
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::meeting_token::{Allowed, MediaKind, TokenPermissions};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let permissions = TokenPermissions {
+    ///     can_send: Some(Allowed::Only(vec![MediaKind::Audio])),
+    ///     ..TokenPermissions::default()
+    /// };
+    /// client
+    ///     .update_participant("a-room", "a-session-id", &permissions)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_participant(
+        &self,
+        room_name: &str,
+        session_id: &str,
+        permissions: &TokenPermissions,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct UpdatePermissions<'a> {
+            data: BTreeMap<&'a str, &'a TokenPermissions>,
+        }
+
+        let url = self.get_room_url_with_name(&format!("{room_name}/update-permissions"));
+        let body = UpdatePermissions {
+            data: BTreeMap::from([(session_id, permissions)]),
+        };
+        let resp = self.execute(self.post_json(url, &body)).await?;
+
+        if resp.status().is_success() {
+            Ok(())
         } else {
-            Ok(data.data)
+            Err(Error::from_failed_daily_request(resp).await)
         }
     }
 
+    /// Retrieve the logs and connection quality metrics of a meeting session, e.g. the
+    /// [meeting_session_id](crate::recording::RecordingObject::meeting_session_id) of a
+    /// recording, to look into a participant's call being bad.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use uuid::Uuid;
+    /// # async fn run(meeting_session_id: Uuid) -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let logs = client.get_meeting_logs(meeting_session_id).await?;
+    /// let worst_loss = logs
+    ///     .metrics
+    ///     .iter()
+    ///     .filter_map(|sample| sample.video_recv_packet_loss)
+    ///     .fold(0.0, f64::max);
+    /// println!("up to {:.0}% of video was lost", worst_loss * 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_meeting_logs(&self, meeting_session_id: Uuid) -> Result<MeetingLogs> {
+        // This should not be able to fail
+        let url = self.base_url.join("logs").unwrap();
+        let request = self.get(url).query(&[
+            ("mtgSessionId", meeting_session_id.to_string().as_str()),
+            ("includeLogs", "true"),
+            ("includeMetrics", "true"),
+        ]);
+        let resp = self.execute(request).await?;
+        parse_dailyco_response(resp).await
+    }
+
     /// Get information about a specific recording.
     ///
     /// <https://docs.daily.co/reference/rest-api/recordings/get-recording-information>
-    pub async fn get_recording(&self, id: Uuid) -> Result<RecordingObject> {
-        let url = format!("{}/recordings/{id}", self.base_url);
-        let resp = self.client.get(url).send().await?;
-        let data: RecordingObject = parse_dailyco_response(resp).await?;
-        Ok(data)
+    pub async fn get_recording(&self, id: impl Into<RecordingId>) -> Result<RecordingObject> {
+        self.get_recording_by_id(&id.into().to_string()).await
+    }
+
+    /// Get information about the recording with this id, which is used as-is rather
+    /// than parsed as a [RecordingId], e.g. one taken from a webhook payload.
+    ///
+    /// Prefer [get_recording](Self::get_recording) when the id is known to be valid.
+    pub async fn get_recording_by_id(&self, id: &str) -> Result<RecordingObject> {
+        let url = self.get_recording_url_with_id(id)?;
+        let resp = self.execute(self.get(url)).await?;
+        parse_dailyco_response(resp).await
+    }
+
+    /// Poll the recording with this id every `poll_interval` until its status is
+    /// [terminal](crate::recording::RecordingStatus::is_terminal), returning the
+    /// recording as last fetched.
+    ///
+    /// Dropping the returned future stops polling, no requests are made in the background.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Timeout`](crate::Error::Timeout) if the recording is still in progress
+    /// after `timeout`, or the error of any failed request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::recording::{RecordingId, RecordingObject};
+    /// # async fn run(id: RecordingId) -> Result<RecordingObject> {
+    /// let client = Client::new("test-api-key")?;
+    /// let recording = client
+    ///     .poll_recording_until_ready(id, Duration::from_secs(5), Duration::from_secs(600))
+    ///     .await?;
+    /// # Ok(recording)
+    /// # }
+    /// ```
+    pub async fn poll_recording_until_ready(
+        &self,
+        id: impl Into<RecordingId>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<RecordingObject> {
+        let id = id.into();
+        let poll = async {
+            loop {
+                let recording = self.get_recording(id).await?;
+                if recording.status.is_terminal() {
+                    return Ok(recording);
+                }
+                Delay::new(poll_interval).await;
+            }
+        };
+        pin_mut!(poll);
+        match future::select(poll, Delay::new(timeout)).await {
+            Either::Left((res, _)) => res,
+            Either::Right(((), _)) => Err(Error::Timeout(timeout)),
+        }
+    }
+
+    /// Get a fresh access link for the recording with this id and download the
+    /// recording with it, returning the whole .mp4 file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let id = "f9fb1e30-6ce4-4ca6-9aaa-3c3c4a4dcb9e".parse::<dailyco::recording::RecordingId>().unwrap();
+    /// let mp4 = client.download_recording(id).await?;
+    /// std::fs::write("recording.mp4", &mp4).unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_recording(&self, id: impl Into<RecordingId>) -> Result<Bytes> {
+        let link = GetRecordingAccessLink::new().send(self, id).await?;
+        link.download_bytes(self).await
+    }
+
+    /// Get every recording of the room with this name, most recent first, fetching as
+    /// many pages as needed.
+    ///
+    /// All recordings are loaded into memory, so `max` can be used to stop after that many
+    /// recordings. To process recordings as they arrive, use
+    /// [`ListRecordings::into_stream`](crate::recording::ListRecordings::into_stream) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::recording::RecordingObject;
+    /// # async fn run() -> Result<Vec<RecordingObject>> {
+    /// let client = Client::new("test-api-key")?;
+    /// let latest_recordings = client.get_recordings_for_room("a-room", Some(10)).await?;
+    /// # Ok(latest_recordings)
+    /// # }
+    /// ```
+    pub async fn get_recordings_for_room(
+        &self,
+        room_name: &str,
+        max: Option<usize>,
+    ) -> Result<Vec<RecordingObject>> {
+        let mut request = ListRecordings::new();
+        request.room_name(room_name);
+        let recordings = request.into_stream(self);
+        match max {
+            Some(max) => recordings.take(max).try_collect().await,
+            None => recordings.try_collect().await,
+        }
     }
 
     /// Delete a specific recording
     ///
     /// <https://docs.daily.co/reference/rest-api/recordings/delete-recording>
-    pub async fn delete_recording(&self, id: Uuid) -> Result<()> {
-        let url = format!("{}/recordings/{id}", self.base_url);
-        let resp = self.client.delete(url).send().await?;
+    pub async fn delete_recording(&self, id: impl Into<RecordingId>) -> Result<()> {
+        self.delete_recording_by_id(&id.into().to_string()).await
+    }
+
+    /// Delete the recording with this id, which is used as-is rather than parsed as a
+    /// [RecordingId], e.g. one taken from a webhook payload.
+    ///
+    /// Prefer [delete_recording](Self::delete_recording) when the id is known to be valid.
+    pub async fn delete_recording_by_id(&self, id: &str) -> Result<()> {
+        let url = self.get_recording_url_with_id(id)?;
+        let resp = self.execute(self.delete(url)).await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -203,7 +1305,7 @@ impl Client {
     /// ```
     pub async fn delete_room(&self, room_name: &str) -> Result<()> {
         let url = self.get_room_url_with_name(room_name);
-        let resp = self.client.delete(url).send().await?;
+        let resp = self.execute(self.delete(url)).await?;
 
         if resp.status().is_success() {
             Ok(())
@@ -212,6 +1314,72 @@ impl Client {
         }
     }
 
+    /// Delete the `Daily` room with this name if it exists, returning whether
+    /// a room was deleted.
+    ///
+    /// Unlike [`delete_room`](Self::delete_room), a room which does not exist is not an error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<bool> {
+    /// let client = Client::new("test-api-key")?;
+    /// let deleted = client.delete_room_if_exists("maybe-a-room").await?;
+    /// # Ok(deleted)
+    /// # }
+    /// ```
+    pub async fn delete_room_if_exists(&self, room_name: &str) -> Result<bool> {
+        match self.delete_room(room_name).await {
+            Ok(()) => Ok(true),
+            Err(err) if err.is_not_found() => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Delete the `Daily` rooms with these names, making up to `concurrency` requests at a time.
+    ///
+    /// One result is returned per name, in the same order as `names`. If `missing_ok` is set,
+    /// rooms which do not exist are treated as successfully deleted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let results = client
+    ///     .delete_rooms(&["room-1", "room-2", "room-3"], 2, true)
+    ///     .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_rooms(
+        &self,
+        names: &[&str],
+        concurrency: usize,
+        missing_ok: bool,
+    ) -> Vec<Result<()>> {
+        // Collected up front so the stream's type doesn't include a closure, which
+        // would stop the returned future from being provably `Send`.
+        let deletions: Vec<_> = names
+            .iter()
+            .map(|name| self.delete_one_of_rooms(name, missing_ok))
+            .collect();
+        stream::iter(deletions)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    async fn delete_one_of_rooms(&self, room_name: &str, missing_ok: bool) -> Result<()> {
+        if missing_ok {
+            self.delete_room_if_exists(room_name).await.map(|_| ())
+        } else {
+            self.delete_room(room_name).await
+        }
+    }
+
     fn get_room_url_with_name(&self, room_name: &str) -> Url {
         // Neither of these unwraps should be able to fail
         self.base_url
@@ -220,12 +1388,65 @@ impl Client {
             .join(room_name)
             .unwrap()
     }
+
+    /// The URL of the recording with this id, which is added as a single path segment so
+    /// that an id from elsewhere can't point the request at another path or host.
+    fn get_recording_url_with_id(&self, id: &str) -> Result<Url> {
+        if id.is_empty() || id == "." || id == ".." {
+            return Err(Error::invalid_config(format!(
+                "`{id}` is not a valid recording id"
+            )));
+        }
+        let mut url = Url::clone(&self.base_url);
+        url.path_segments_mut()
+            .map_err(|()| Error::invalid_config("the endpoint can't have a path appended"))?
+            .pop_if_empty()
+            .extend(["recordings", id]);
+        Ok(url)
+    }
 }
 
 pub async fn parse_dailyco_response<T: DeserializeOwned>(resp: Response) -> Result<T> {
     if resp.status().is_success() {
-        Ok(resp.json().await?)
+        let request_id = crate::error::request_id(&resp);
+        // A missing content type is let through, the body is still checked below
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned())
+            .filter(|content_type| !is_json(content_type));
+        // Read the body first so it can be reported if it doesn't deserialize
+        let body = resp.text().await?;
+        if let Some(content_type) = content_type {
+            return Err(Error::UnexpectedContentType {
+                content_type,
+                body_snippet: snippet(&body).to_string(),
+            });
+        }
+        serde_json::from_str(&body).map_err(|source| Error::Deserialize {
+            body,
+            request_id,
+            source,
+        })
     } else {
         Err(Error::from_failed_daily_request(resp).await)
     }
 }
+
+/// Whether a `Content-Type` is JSON, ignoring parameters like the charset.
+fn is_json(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+    essence.eq_ignore_ascii_case("application/json")
+        || essence.to_ascii_lowercase().ends_with("+json")
+}
+
+/// Maximum length of the body kept in [Error::UnexpectedContentType].
+const BODY_SNIPPET_LEN: usize = 200;
+
+/// The start of `body`, cut at a character boundary.
+fn snippet(body: &str) -> &str {
+    match body.char_indices().nth(BODY_SNIPPET_LEN) {
+        Some((end, _)) => &body[..end],
+        None => body,
+    }
+}
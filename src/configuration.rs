@@ -1,9 +1,88 @@
 //! Miscellaneous enum definitions for `Daily` configuration options.
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::{Error, Result};
+
+/// Implement `all`, `as_str`, `FromStr` and `Display` for a fieldless enum from the
+/// string form of each variant. Any aliases after the string form are also accepted
+/// when parsing. Every variant must be listed, or `as_str` won't compile.
+///
+/// The conversions to and from strings are also implemented, so that the enum can be
+/// (de)serialized through them with `#[serde(try_from = "String", into = "&'static str")]`.
+macro_rules! string_enum {
+    ($name:ident { $($variant:ident => $value:literal $(| $alias:literal)*),+ $(,)? }) => {
+        impl $name {
+            /// Every value, in declaration order.
+            pub fn all() -> &'static [Self] {
+                &[$(Self::$variant),+]
+            }
+
+            /// The string form of this value, as used by `Daily`.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                match s {
+                    $($value $(| $alias)* => Ok(Self::$variant),)+
+                    _ => Err(ParseError {
+                        kind: stringify!($name),
+                        value: s.to_string(),
+                    }),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl From<$name> for &'static str {
+            fn from(value: $name) -> Self {
+                value.as_str()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ParseError;
+
+            fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+    };
+}
+
+/// The error returned when parsing a configuration option from a string which isn't
+/// one of its values.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    kind: &'static str,
+    value: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a known {}", self.value, self.kind)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Signaling server region for hosting a call
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "kebab-case")]
+#[serde(try_from = "String", into = "&'static str")]
 #[non_exhaustive]
 pub enum Region {
     /// Cape Town
@@ -28,9 +107,67 @@ pub enum Region {
     UsWest2,
 }
 
+string_enum!(Region {
+    AfSouth1 => "af-south-1",
+    ApNortheast2 => "ap-northeast-2",
+    ApSoutheast1 => "ap-southeast-1",
+    ApSoutheast2 => "ap-southeast-2",
+    ApSouth1 => "ap-south-1",
+    EuCentral1 => "eu-central-1",
+    EuWest2 => "eu-west-2",
+    SaEast1 => "sa-east-1",
+    UsEast1 => "us-east-1",
+    UsWest2 => "us-west-2",
+});
+
+impl Region {
+    /// The region nearest to the country with this ISO 3166-1 alpha-2 code (e.g. `"DE"`),
+    /// case-insensitively, or `None` if the code isn't recognized.
+    ///
+    /// This is a static, geographic mapping rather than a latency measurement, which
+    /// gives a sensible `geo` for users or servers in that country.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::configuration::Region;
+    /// assert_eq!(Region::for_country("br"), Some(Region::SaEast1));
+    /// let geo = Region::for_country("XX").unwrap_or(Region::UsEast1);
+    /// assert_eq!(geo, Region::UsEast1);
+    /// ```
+    pub fn for_country(country_code: &str) -> Option<Self> {
+        let code = country_code.to_ascii_uppercase();
+        let region =
+            match code.as_str() {
+                "AO" | "BI" | "BW" | "CD" | "CG" | "CM" | "ET" | "GA" | "GH" | "KE" | "LS"
+                | "MG" | "MU" | "MW" | "MZ" | "NA" | "NG" | "RW" | "SZ" | "TZ" | "UG" | "ZA"
+                | "ZM" | "ZW" => Self::AfSouth1,
+                "CN" | "HK" | "JP" | "KP" | "KR" | "MN" | "MO" | "TW" => Self::ApNortheast2,
+                "BN" | "ID" | "KH" | "LA" | "MM" | "MY" | "PH" | "SG" | "TH" | "TL" | "VN" => {
+                    Self::ApSoutheast1
+                }
+                "AU" | "FJ" | "NC" | "NZ" | "PG" | "SB" | "VU" | "WS" | "TO" => Self::ApSoutheast2,
+                "AE" | "BD" | "BH" | "BT" | "IN" | "KW" | "LK" | "MV" | "NP" | "OM" | "PK"
+                | "QA" | "SA" => Self::ApSouth1,
+                "AL" | "AM" | "AT" | "AZ" | "BA" | "BG" | "BY" | "CH" | "CY" | "CZ" | "DE"
+                | "DK" | "DZ" | "EE" | "EG" | "FI" | "GE" | "GR" | "HR" | "HU" | "IL" | "IQ"
+                | "IR" | "IT" | "JO" | "KZ" | "LB" | "LI" | "LT" | "LU" | "LV" | "LY" | "MA"
+                | "MD" | "ME" | "MK" | "MT" | "NL" | "NO" | "PL" | "PS" | "RO" | "RS" | "RU"
+                | "SE" | "SI" | "SK" | "TN" | "TR" | "UA" | "UZ" => Self::EuCentral1,
+                "BE" | "ES" | "FR" | "GB" | "IE" | "IS" | "PT" => Self::EuWest2,
+                "AR" | "BO" | "BR" | "CL" | "CO" | "EC" | "GY" | "PE" | "PY" | "SR" | "UY"
+                | "VE" => Self::SaEast1,
+                "BS" | "BZ" | "CA" | "CR" | "CU" | "DO" | "GT" | "HN" | "HT" | "JM" | "MX"
+                | "NI" | "PA" | "PR" | "SV" | "TT" | "US" => Self::UsEast1,
+                _ => return None,
+            };
+        Some(region)
+    }
+}
+
 /// Used to select the region where an RTMP stream should originate.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "kebab-case")]
+#[serde(try_from = "String", into = "&'static str")]
 #[non_exhaustive]
 pub enum RtmpGeoRegion {
     /// Oregon
@@ -41,12 +178,24 @@ pub enum RtmpGeoRegion {
     ApSoutheast1,
 }
 
-/// Options for the default language of a Daily room
+string_enum!(RtmpGeoRegion {
+    UsWest2 => "us-west-2",
+    EuCentral1 => "eu-central-1",
+    ApSoutheast1 => "ap-southeast-1",
+});
+
+/// Options for the default language of the `Daily Prebuilt` UI, used by the `lang`
+/// of both rooms and meeting tokens.
+///
+/// These are `Daily Prebuilt`'s own codes, which aren't all ISO 639-1: Japanese is
+/// `jp` rather than `ja`. `ja` is still accepted when deserializing or parsing.
+/// Transcription uses ISO codes instead, see [TranscriptionLanguage].
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "lowercase")]
+#[serde(try_from = "String", into = "&'static str")]
 #[non_exhaustive]
 #[allow(missing_docs)]
 pub enum DailyLang {
+    Da,
     De,
     En,
     Es,
@@ -54,10 +203,13 @@ pub enum DailyLang {
     Fr,
     It,
     Jp,
+    /// Georgian
     Ka,
     Nl,
     No,
     Pt,
+    /// Brazilian Portuguese
+    PtBr,
     Pl,
     Ru,
     Sv,
@@ -66,6 +218,27 @@ pub enum DailyLang {
     User,
 }
 
+string_enum!(DailyLang {
+    Da => "da",
+    De => "de",
+    En => "en",
+    Es => "es",
+    Fi => "fi",
+    Fr => "fr",
+    It => "it",
+    Jp => "jp" | "ja",
+    Ka => "ka",
+    Nl => "nl",
+    No => "no",
+    Pt => "pt",
+    PtBr => "pt-BR",
+    Pl => "pl",
+    Ru => "ru",
+    Sv => "sv",
+    Tr => "tr",
+    User => "user",
+});
+
 impl Default for DailyLang {
     /// Matching Daily documented default
     fn default() -> Self {
@@ -90,17 +263,45 @@ pub enum RecordingType {
 }
 
 /// Signaling type, seen in <https://docs.daily.co/reference/rest-api/rooms/config#signaling_imp>
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum SignalingImp {
     /// `ws` signaling type
+    #[default]
     Ws,
 }
 
-impl Default for SignalingImp {
-    fn default() -> Self {
-        Self::Ws
+/// When a room's topology switches from Peer-to-Peer (P2P) to Selective Forwarding
+/// Unit (SFU), as described [here](https://docs.daily.co/reference/rest-api/rooms/config#sfu_switchover).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SfuSwitchover {
+    /// Use SFU from the first participant.
+    Always,
+    /// Switch to SFU once the room has more than this many participants.
+    AtCount(u32),
+    /// Stay P2P however many participants join.
+    ///
+    /// `Daily` doesn't document a value for this, so by this crate's convention it is
+    /// sent as a participant count of `u32::MAX`, which no room can reach.
+    Never,
+}
+
+impl SfuSwitchover {
+    /// The `sfu_switchover` value `Daily` expects: `0.5` for [Always](Self::Always),
+    /// and a participant count otherwise, `u32::MAX` for [Never](Self::Never).
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Self::Always => 0.5,
+            Self::AtCount(count) => f64::from(count),
+            Self::Never => f64::from(u32::MAX),
+        }
+    }
+}
+
+impl From<SfuSwitchover> for f64 {
+    fn from(switchover: SfuSwitchover) -> Self {
+        switchover.as_f64()
     }
 }
 
@@ -110,14 +311,433 @@ pub struct RecordingsBucket {
     /// The name of the Amazon S3 bucket to use for recording storage.
     pub bucket_name: String,
     /// The region which the specified S3 bucket is located in.
-    pub bucket_region: String,
+    pub bucket_region: AwsRegion,
     /// The Amazon Resource Name (ARN) of the role Daily should assume
     /// when storing the recording in the specified bucket.
     pub assume_role_arn: String,
     /// Whether the recording should be accessible using Daily's API.
     pub allow_api_access: bool,
     /// Specifies which Content-Disposition response header the recording link retrieved
-    /// from the access-link REST API endpoint will have.
+    /// from the access-link REST API endpoint will have: `inline` when set, so the
+    /// recording can be streamed, otherwise `attachment`. See
+    /// [RecordingAccessLink::is_streamable](crate::recording::RecordingAccessLink::is_streamable).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_streaming_from_bucket: Option<bool>,
 }
+
+impl RecordingsBucket {
+    /// Start a [RecordingsBucketBuilder], which checks the bucket configuration
+    /// looks valid before `Daily` tries to use it.
+    pub fn builder() -> RecordingsBucketBuilder {
+        RecordingsBucketBuilder::default()
+    }
+}
+
+/// Implement [AwsRegion], with a variant for each known region and `Other` for the
+/// rest, along with its conversions to and from strings.
+macro_rules! aws_regions {
+    ($($(#[$meta:meta])* $variant:ident => $value:literal),+ $(,)?) => {
+        /// The AWS region an S3 bucket is located in.
+        ///
+        /// Regions this crate doesn't know about, like ones newer than it, are kept as
+        /// [`Other`](Self::Other) rather than failing to deserialize.
+        #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        #[non_exhaustive]
+        pub enum AwsRegion {
+            $($(#[$meta])* $variant,)+
+            /// A region not known to this crate, as its string form.
+            Other(String),
+        }
+
+        impl AwsRegion {
+            /// The string form of this region, as used by AWS, e.g. `us-west-2`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Other(region) => region,
+                }
+            }
+        }
+
+        impl From<&str> for AwsRegion {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)+
+                    _ => Self::Other(value.to_string()),
+                }
+            }
+        }
+    };
+}
+
+aws_regions! {
+    /// Cape Town
+    AfSouth1 => "af-south-1",
+    /// Hong Kong
+    ApEast1 => "ap-east-1",
+    /// Tokyo
+    ApNortheast1 => "ap-northeast-1",
+    /// Seoul
+    ApNortheast2 => "ap-northeast-2",
+    /// Osaka
+    ApNortheast3 => "ap-northeast-3",
+    /// Mumbai
+    ApSouth1 => "ap-south-1",
+    /// Hyderabad
+    ApSouth2 => "ap-south-2",
+    /// Singapore
+    ApSoutheast1 => "ap-southeast-1",
+    /// Sydney
+    ApSoutheast2 => "ap-southeast-2",
+    /// Jakarta
+    ApSoutheast3 => "ap-southeast-3",
+    /// Melbourne
+    ApSoutheast4 => "ap-southeast-4",
+    /// Canada Central
+    CaCentral1 => "ca-central-1",
+    /// Calgary
+    CaWest1 => "ca-west-1",
+    /// Beijing
+    CnNorth1 => "cn-north-1",
+    /// Ningxia
+    CnNorthwest1 => "cn-northwest-1",
+    /// Frankfurt
+    EuCentral1 => "eu-central-1",
+    /// Zurich
+    EuCentral2 => "eu-central-2",
+    /// Stockholm
+    EuNorth1 => "eu-north-1",
+    /// Milan
+    EuSouth1 => "eu-south-1",
+    /// Spain
+    EuSouth2 => "eu-south-2",
+    /// Ireland
+    EuWest1 => "eu-west-1",
+    /// London
+    EuWest2 => "eu-west-2",
+    /// Paris
+    EuWest3 => "eu-west-3",
+    /// Tel Aviv
+    IlCentral1 => "il-central-1",
+    /// UAE
+    MeCentral1 => "me-central-1",
+    /// Bahrain
+    MeSouth1 => "me-south-1",
+    /// Sao Paulo
+    SaEast1 => "sa-east-1",
+    /// N. Virginia
+    UsEast1 => "us-east-1",
+    /// Ohio
+    UsEast2 => "us-east-2",
+    /// GovCloud (US-East)
+    UsGovEast1 => "us-gov-east-1",
+    /// GovCloud (US-West)
+    UsGovWest1 => "us-gov-west-1",
+    /// N. California
+    UsWest1 => "us-west-1",
+    /// Oregon
+    UsWest2 => "us-west-2",
+}
+
+impl AwsRegion {
+    /// Whether this is a region known to this crate, rather than [`Other`](Self::Other).
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Other(_))
+    }
+}
+
+impl From<String> for AwsRegion {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Other(_) => Self::Other(value),
+            region => region,
+        }
+    }
+}
+
+impl From<AwsRegion> for String {
+    fn from(region: AwsRegion) -> Self {
+        match region {
+            AwsRegion::Other(region) => region,
+            region => region.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AwsRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A builder for a [RecordingsBucket], checking the shape of its values when built.
+///
+/// The checks are deliberately light: they catch typos and values in the wrong
+/// field, not whether the bucket or role actually exist.
+///
+/// # Examples
+///
+/// ```
+/// # use dailyco::configuration::RecordingsBucket;
+/// # fn run() -> dailyco::Result<RecordingsBucket> {
+/// let bucket = RecordingsBucket::builder()
+///     .bucket_name("my-recordings")
+///     .bucket_region("eu-west-2")
+///     .assume_role_arn("arn:aws:iam::123456789012:role/daily-recordings")
+///     .allow_api_access(true)
+///     .build()?;
+/// # Ok(bucket)
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct RecordingsBucketBuilder {
+    bucket_name: Option<String>,
+    bucket_region: Option<AwsRegion>,
+    assume_role_arn: Option<String>,
+    allow_api_access: bool,
+    allow_streaming_from_bucket: Option<bool>,
+}
+
+impl RecordingsBucketBuilder {
+    /// The name of the Amazon S3 bucket to use for recording storage.
+    pub fn bucket_name(mut self, bucket_name: impl Into<String>) -> Self {
+        self.bucket_name = Some(bucket_name.into());
+        self
+    }
+
+    /// The AWS region the bucket is located in, e.g. [AwsRegion::UsWest2] or
+    /// `"us-west-2"`.
+    pub fn bucket_region(mut self, bucket_region: impl Into<AwsRegion>) -> Self {
+        self.bucket_region = Some(bucket_region.into());
+        self
+    }
+
+    /// The ARN of the IAM role `Daily` should assume to store recordings, of the
+    /// form `arn:aws:iam::<account id>:role/<role name>`.
+    pub fn assume_role_arn(mut self, assume_role_arn: impl Into<String>) -> Self {
+        self.assume_role_arn = Some(assume_role_arn.into());
+        self
+    }
+
+    /// Whether the recording should be accessible using Daily's API. Defaults to `false`.
+    pub fn allow_api_access(mut self, allow_api_access: bool) -> Self {
+        self.allow_api_access = allow_api_access;
+        self
+    }
+
+    /// Specifies which Content-Disposition response header the recording link retrieved
+    /// from the access-link REST API endpoint will have: `inline` when set, so the
+    /// recording can be streamed, otherwise `attachment`. See
+    /// [RecordingAccessLink::is_streamable](crate::recording::RecordingAccessLink::is_streamable).
+    pub fn allow_streaming_from_bucket(mut self, allow_streaming_from_bucket: bool) -> Self {
+        self.allow_streaming_from_bucket = Some(allow_streaming_from_bucket);
+        self
+    }
+
+    /// Build the [RecordingsBucket].
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`] if a required value is
+    /// missing, the bucket name isn't a valid S3 bucket name, the region isn't a known
+    /// AWS region or the ARN isn't an IAM role ARN.
+    pub fn build(self) -> Result<RecordingsBucket> {
+        let bucket_name = self
+            .bucket_name
+            .ok_or_else(|| Error::invalid_config("recordings bucket requires a bucket_name"))?;
+        let bucket_region = self
+            .bucket_region
+            .ok_or_else(|| Error::invalid_config("recordings bucket requires a bucket_region"))?;
+        let assume_role_arn = self.assume_role_arn.ok_or_else(|| {
+            Error::invalid_config("recordings bucket requires an assume_role_arn")
+        })?;
+
+        if !is_bucket_name(&bucket_name) {
+            return Err(Error::invalid_config(format!(
+                "`{bucket_name}` is not a valid S3 bucket name"
+            )));
+        }
+        if !bucket_region.is_known() {
+            return Err(Error::invalid_config(format!(
+                "`{bucket_region}` is not a known AWS region"
+            )));
+        }
+        if !is_iam_role_arn(&assume_role_arn) {
+            return Err(Error::invalid_config(format!(
+                "`{assume_role_arn}` is not an IAM role ARN, expected `arn:aws:iam::<account id>:role/<role name>`"
+            )));
+        }
+
+        Ok(RecordingsBucket {
+            bucket_name,
+            bucket_region,
+            assume_role_arn,
+            allow_api_access: self.allow_api_access,
+            allow_streaming_from_bucket: self.allow_streaming_from_bucket,
+        })
+    }
+}
+
+fn is_bucket_name(name: &str) -> bool {
+    let valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-';
+    let alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    (3..=63).contains(&name.len())
+        && name.chars().all(valid_char)
+        && name.starts_with(alphanumeric)
+        && name.ends_with(alphanumeric)
+}
+
+fn is_iam_role_arn(arn: &str) -> bool {
+    let mut parts = arn.splitn(6, ':');
+    let (Some("arn"), Some(partition), Some("iam"), Some(""), Some(account), Some(resource)) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return false;
+    };
+    let role_name = resource.strip_prefix("role/").unwrap_or_default();
+    matches!(partition, "aws" | "aws-cn" | "aws-us-gov")
+        && account.len() == 12
+        && account.chars().all(|c| c.is_ascii_digit())
+        && !role_name.is_empty()
+}
+
+/// Language to transcribe speech in, as a BCP-47 tag understood by `Daily`'s
+/// transcription provider.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+#[allow(missing_docs)]
+pub enum TranscriptionLanguage {
+    #[serde(rename = "de")]
+    De,
+    #[serde(rename = "en")]
+    En,
+    #[serde(rename = "en-GB")]
+    EnGb,
+    #[serde(rename = "en-US")]
+    EnUs,
+    #[serde(rename = "es")]
+    Es,
+    #[serde(rename = "fr")]
+    Fr,
+    #[serde(rename = "hi")]
+    Hi,
+    #[serde(rename = "it")]
+    It,
+    #[serde(rename = "ja")]
+    Ja,
+    #[serde(rename = "ko")]
+    Ko,
+    #[serde(rename = "nl")]
+    Nl,
+    #[serde(rename = "pt")]
+    Pt,
+    #[serde(rename = "pt-BR")]
+    PtBr,
+    #[serde(rename = "ru")]
+    Ru,
+    #[serde(rename = "sv")]
+    Sv,
+    #[serde(rename = "tr")]
+    Tr,
+    #[serde(rename = "zh")]
+    Zh,
+    /// Detect and transcribe multiple languages spoken in the same call.
+    #[serde(rename = "multi")]
+    Multi,
+}
+
+/// Speech recognition model used for transcription.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TranscriptionModel {
+    /// `nova-2`
+    #[serde(rename = "nova-2")]
+    Nova2,
+    /// `nova-2-general`
+    #[serde(rename = "nova-2-general")]
+    Nova2General,
+    /// `nova-2-meeting`, tuned for conference rooms with several speakers.
+    #[serde(rename = "nova-2-meeting")]
+    Nova2Meeting,
+    /// `nova-2-phonecall`, tuned for low-bandwidth audio.
+    #[serde(rename = "nova-2-phonecall")]
+    Nova2Phonecall,
+    /// `nova-2-medical`, tuned for medical vocabulary.
+    #[serde(rename = "nova-2-medical")]
+    Nova2Medical,
+    /// `nova-3`
+    #[serde(rename = "nova-3")]
+    Nova3,
+}
+
+/// Pricing tier of the transcription model. Superseded by picking a
+/// [`TranscriptionModel`], but still accepted by `Daily`.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+#[allow(missing_docs)]
+pub enum TranscriptionTier {
+    Base,
+    Enhanced,
+    Nova,
+}
+
+/// Settings for transcription started automatically in a room, detailed
+/// [here](https://docs.daily.co/reference/rest-api/rooms/config#auto_transcription_settings).
+/// Settings left as `None` use `Daily`'s defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AutoTranscriptionSettings {
+    /// The language to transcribe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<TranscriptionLanguage>,
+    /// The speech recognition model to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<TranscriptionModel>,
+    /// The pricing tier of the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<TranscriptionTier>,
+    /// Replace profanity in the transcript with asterisks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profanity_filter: Option<bool>,
+    /// Redact sensitive information, such as numbers, from the transcript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redact: Option<bool>,
+}
+
+/// How SIP participants connect to a room.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum SipMode {
+    /// SIP endpoints dial in to the room.
+    DialIn,
+    /// A mode not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// SIP interconnect settings for a room, detailed
+/// [here](https://docs.daily.co/reference/rest-api/rooms/config#sip).
+/// Settings left as `None` use `Daily`'s defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SipSettings {
+    /// The name SIP participants are shown with in the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Whether SIP participants can send and receive video, rather than audio only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<bool>,
+    /// How SIP participants connect to the room.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sip_mode: Option<SipMode>,
+    /// The number of SIP endpoints which can be connected to the room at once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_endpoints: Option<u32>,
+}
@@ -1,12 +1,15 @@
 use std::fmt::Formatter;
 
-use reqwest::Response;
+use reqwest::{Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// A `Result` alias where the `Err` case is `dailyco::Error`.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The error parsing a [reqwest::Url], which `reqwest` doesn't re-export by name.
+type UrlParseError = <reqwest::Url as std::str::FromStr>::Err;
+
 /// The possible errors when making requests to `Daily`.
 #[derive(Error, Debug)]
 pub enum Error {
@@ -14,28 +17,187 @@ pub enum Error {
     #[error("failure making the request")]
     Request(#[from] reqwest::Error),
     /// Error reported by `Daily`.
-    #[error("daily request returned an error: {0}")]
-    APIError(DailyCoErrorInfo),
+    #[error("daily request returned an error")]
+    APIError(#[from] DailyCoErrorInfo),
     /// Invalid API key.
     #[error("API key problem: {0}")]
     BadAPIKey(&'static str),
-    /// Request which requires pagination to return full result, unimplemented.
-    #[error("Response requires pagination, which is not implemented yet.")]
+    /// The endpoint given for the `Daily` API isn't a valid URL.
+    #[error("invalid endpoint `{endpoint}`")]
+    BadEndpoint {
+        /// The endpoint which was given.
+        endpoint: String,
+        /// Why it isn't a valid URL.
+        source: UrlParseError,
+    },
+    /// Configuration rejected before making any request, e.g. a malformed value.
+    #[error("invalid configuration: {reason}")]
+    InvalidConfig {
+        /// What is wrong with the configuration.
+        reason: String,
+    },
+    /// A successful response from `Daily` did not have the expected shape.
+    #[error("failed to deserialize the daily response")]
+    Deserialize {
+        /// The raw body of the response.
+        body: String,
+        /// The id `Daily` assigned to the request, if it returned one.
+        request_id: Option<String>,
+        /// The underlying deserialization error.
+        source: serde_json::Error,
+    },
+    /// A successful response was not JSON, typically an error page served by a proxy
+    /// or CDN in front of `Daily`.
+    #[error("expected a JSON response, found content type {content_type:?}")]
+    UnexpectedContentType {
+        /// The `Content-Type` the response declared.
+        content_type: String,
+        /// The start of the response body, to help tell where it came from.
+        body_snippet: String,
+    },
+    /// The signed download link of a recording was rejected, usually because it
+    /// has expired. A fresh link can be requested with
+    /// [`GetRecordingAccessLink`](crate::recording::GetRecordingAccessLink).
+    #[error("recording download link was rejected, it expired at {expires}")]
+    RecordingLinkExpired {
+        /// The unix timestamp the link was valid until.
+        expires: i64,
+    },
+    /// A self-signed meeting token was malformed or has expired.
+    #[cfg(feature = "self-signed-tokens")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+    #[error("meeting token is invalid")]
+    InvalidMeetingToken(#[source] jsonwebtoken::errors::Error),
+    /// A self-signed meeting token was not signed by any of the given secret keys.
+    #[cfg(feature = "self-signed-tokens")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+    #[error("meeting token was not signed with any of the given keys")]
+    MeetingTokenKeyMismatch,
+    /// An operation waiting on `Daily` didn't complete in time.
+    #[error("timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    /// More results were found than fit in a single page, which
+    /// [GetRooms](crate::room::GetRooms) can page through.
+    #[error("response requires pagination, use GetRooms to page through it")]
     RequiresPagination,
 }
 
 impl Error {
+    pub(crate) fn invalid_config(reason: impl Into<String>) -> Self {
+        Self::InvalidConfig {
+            reason: reason.into(),
+        }
+    }
+
+    /// Whether this is `Daily` reporting the requested item does not exist.
+    pub(crate) fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::APIError(DailyCoErrorInfo {
+                error: Some(DailyCoErrorKind::NotFound),
+                ..
+            })
+        )
+    }
+
     pub(crate) async fn from_failed_daily_request(response: Response) -> Self {
-        match response.json().await {
-            Ok(error) => Self::APIError(error),
+        let request_id = request_id(&response);
+        let status = response.status();
+        match response.json::<DailyCoErrorInfo>().await {
+            Ok(error) => Self::APIError(DailyCoErrorInfo {
+                error: error.error.map(|kind| kind.refine(error.info.as_deref())),
+                request_id: error.request_id.or(request_id),
+                ..error
+            }),
+            // Rate limits are sometimes answered with an HTML or empty body, but should
+            // still be recognisable to back off from
+            Err(_) if status == StatusCode::TOO_MANY_REQUESTS => Self::APIError(DailyCoErrorInfo {
+                error: Some(DailyCoErrorKind::RateLimitError),
+                info: None,
+                request_id,
+            }),
             Err(err) => Self::Request(err),
         }
     }
+
+    /// Whether the request timed out, or an operation waiting on `Daily` (like
+    /// [poll_recording_until_ready](crate::Client::poll_recording_until_ready)) did.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Request(err) => err.is_timeout(),
+            Self::Timeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the connection to `Daily` couldn't be established, e.g. because of a
+    /// DNS or TLS failure. Always `false` on `wasm32` targets, where the browser
+    /// doesn't report this.
+    pub fn is_connect(&self) -> bool {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Request(err) => err.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Whether reading or decoding the response body failed, including a successful
+    /// response not having the expected shape.
+    pub fn is_body(&self) -> bool {
+        match self {
+            Self::Request(err) => err.is_body() || err.is_decode(),
+            Self::Deserialize { .. } | Self::UnexpectedContentType { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the API key is the problem: either it isn't valid in a header, or `Daily`
+    /// rejected it with an [`AuthenticationError`](DailyCoErrorKind::AuthenticationError)
+    /// or [`AuthorizationHeaderError`](DailyCoErrorKind::AuthorizationHeaderError).
+    pub fn is_bad_api_key(&self) -> bool {
+        matches!(
+            self,
+            Self::BadAPIKey(_)
+                | Self::APIError(DailyCoErrorInfo {
+                    error: Some(
+                        DailyCoErrorKind::AuthenticationError
+                            | DailyCoErrorKind::AuthorizationHeaderError
+                    ),
+                    ..
+                })
+        )
+    }
+
+    /// The id `Daily` assigned to the failed request, if it returned one. `Daily` support
+    /// can use this to look into a specific request.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::APIError(info) => info.request_id.as_deref(),
+            Self::Deserialize { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Response headers which may carry the id of a request, in order of preference.
+const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "x-amzn-requestid"];
+
+/// The id of the request this is the response to, if its headers contain one.
+pub(crate) fn request_id(response: &Response) -> Option<String> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .filter_map(|name| response.headers().get(*name))
+        .find_map(|value| value.to_str().ok())
+        .map(str::to_string)
 }
 
 /// The `error` type returned by `Daily`, defined [here](https://docs.daily.co/reference/rest-api#errors).
-#[derive(Debug, Copy, Clone, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "kebab-case")]
+///
+/// Error types this crate doesn't know about yet deserialize as
+/// [`Unknown`](DailyCoErrorKind::Unknown), keeping the type `Daily` returned.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
 pub enum DailyCoErrorKind {
     /// The API key is not valid.
     AuthenticationError,
@@ -53,20 +215,81 @@ pub enum DailyCoErrorKind {
     ServerError,
     /// Item not found.
     NotFound,
+    /// A meeting token is past its `exp`. `Daily` reports this as an
+    /// `invalid-request-error`, which is told apart from a malformed token by its `info`.
+    TokenExpired,
+    /// An error type not known to this crate, e.g. one added by `Daily` since it was released.
+    Unknown(String),
 }
 
-impl std::fmt::Display for DailyCoErrorKind {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let disp = match self {
+impl DailyCoErrorKind {
+    /// The error type as returned by `Daily`, e.g. `"not-found"`. This is
+    /// `"invalid-request-error"` for [`TokenExpired`](Self::TokenExpired).
+    pub fn as_str(&self) -> &str {
+        match self {
             Self::AuthenticationError => "authentication-error",
             Self::AuthorizationHeaderError => "authorization-header-error",
             Self::JsonParsingError => "json-parsing-error",
-            Self::InvalidRequestError => "invalid-request-error",
+            Self::InvalidRequestError | Self::TokenExpired => "invalid-request-error",
             Self::RateLimitError => "rate-limit-error",
             Self::ServerError => "server-error",
             Self::NotFound => "not-found",
-        };
-        f.write_str(disp)
+            Self::Unknown(kind) => kind,
+        }
+    }
+
+    /// Tell apart the kinds `Daily` only distinguishes in the error `info`.
+    fn refine(self, info: Option<&str>) -> Self {
+        match (self, info) {
+            (Self::InvalidRequestError, Some(info)) if is_token_expired(info) => Self::TokenExpired,
+            (kind, _) => kind,
+        }
+    }
+}
+
+/// The `info` messages `Daily` returns for a meeting token past its `exp`.
+const TOKEN_EXPIRED_INFOS: &[&str] = &[
+    "token expired",
+    "token has expired",
+    "meeting token expired",
+    "meeting token has expired",
+];
+
+fn is_token_expired(info: &str) -> bool {
+    let info = info.trim().trim_end_matches('.').to_lowercase();
+    TOKEN_EXPIRED_INFOS.contains(&info.as_str())
+}
+
+impl From<String> for DailyCoErrorKind {
+    fn from(kind: String) -> Self {
+        match kind.as_str() {
+            "authentication-error" => Self::AuthenticationError,
+            "authorization-header-error" => Self::AuthorizationHeaderError,
+            "json-parsing-error" => Self::JsonParsingError,
+            "invalid-request-error" => Self::InvalidRequestError,
+            "rate-limit-error" => Self::RateLimitError,
+            "server-error" => Self::ServerError,
+            "not-found" => Self::NotFound,
+            _ => Self::Unknown(kind),
+        }
+    }
+}
+
+impl From<DailyCoErrorKind> for String {
+    fn from(kind: DailyCoErrorKind) -> Self {
+        match kind {
+            DailyCoErrorKind::Unknown(kind) => kind,
+            kind => kind.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for DailyCoErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TokenExpired => f.write_str("invalid-request-error (token expired)"),
+            kind => f.write_str(kind.as_str()),
+        }
     }
 }
 
@@ -77,17 +300,108 @@ pub struct DailyCoErrorInfo {
     pub error: Option<DailyCoErrorKind>,
     /// Informational description about the error.
     pub info: Option<String>,
+    /// The id `Daily` assigned to the failed request, taken from the response headers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl DailyCoErrorInfo {
+    /// The problem described by the `info` of an
+    /// [`InvalidRequestError`](DailyCoErrorKind::InvalidRequestError), parsed for the
+    /// most common messages. `None` for any other kind of error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Error, InvalidRequestDetail, Result};
+    /// # use dailyco::room::{CreateRoom, Room};
+    /// # async fn run() -> Result<Option<Room>> {
+    /// let client = Client::new("test-api-key")?;
+    /// match CreateRoom::new().name("a-room").send(&client).await {
+    ///     Ok(room) => Ok(Some(room)),
+    ///     Err(Error::APIError(info))
+    ///         if info.invalid_request_detail() == Some(InvalidRequestDetail::NameTaken) =>
+    ///     {
+    ///         Ok(None)
+    ///     }
+    ///     Err(err) => Err(err),
+    /// }
+    /// # }
+    /// ```
+    pub fn invalid_request_detail(&self) -> Option<InvalidRequestDetail> {
+        match (&self.error, &self.info) {
+            (Some(DailyCoErrorKind::InvalidRequestError), Some(info)) => {
+                Some(InvalidRequestDetail::parse(info))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The problem with an invalid request, parsed from the free text `info` `Daily`
+/// returns with an [`InvalidRequestError`](DailyCoErrorKind::InvalidRequestError).
+///
+/// Only stable, common messages are recognised, anything else is kept as
+/// [`Other`](Self::Other). The raw message is still available as
+/// [`DailyCoErrorInfo::info`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidRequestDetail {
+    /// An `exp` was not in the future.
+    ExpInPast,
+    /// A room with the requested name already exists.
+    NameTaken,
+    /// A required field was missing, with the name `Daily` gave it.
+    MissingField(String),
+    /// A message not recognised by this crate.
+    Other(String),
+}
+
+impl InvalidRequestDetail {
+    fn parse(info: &str) -> Self {
+        let lowercase = info.to_lowercase();
+        let mentions_exp = lowercase.starts_with("exp ") || lowercase.contains(" exp ");
+        if lowercase.contains("already exists") {
+            Self::NameTaken
+        } else if mentions_exp && (lowercase.contains("future") || lowercase.contains("past")) {
+            Self::ExpInPast
+        } else if let Some(field) = missing_field(info) {
+            Self::MissingField(field)
+        } else {
+            Self::Other(info.to_string())
+        }
+    }
+}
+
+/// The field named by messages like `missing required parameter: name` or
+/// `'name' is required`.
+fn missing_field(info: &str) -> Option<String> {
+    let field = match info.get(..17) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("missing required ") => {
+            // Skip the word for what is missing, e.g. "field" or "parameter"
+            info[17..].split_once(' ')?.1
+        }
+        _ => info.strip_suffix(" is required")?,
+    };
+    let field = field.trim_matches(|c: char| matches!(c, '\'' | '"' | '`' | ':' | ' '));
+    (!field.is_empty()).then(|| field.to_string())
 }
 
 impl std::fmt::Display for DailyCoErrorInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(error_kind) = self.error {
+        if let Some(error_kind) = &self.error {
             write!(f, "Error: {}", error_kind)?;
         }
         if let Some(info) = &self.info {
-            write!(f, "Info: {}", info)
+            write!(f, "Info: {}", info)?;
         } else {
-            f.write_str("Info: Unspecified")
+            f.write_str("Info: Unspecified")?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request id: {})", request_id)?;
         }
+        Ok(())
     }
 }
+
+impl std::error::Error for DailyCoErrorInfo {}
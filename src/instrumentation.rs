@@ -0,0 +1,49 @@
+//! Counters of requests to `Daily`, emitted through the `metrics` facade.
+use reqwest::{Method, Response, Url};
+
+const REQUESTS_TOTAL: &str = "dailyco_requests_total";
+
+/// Count a request to `Daily`, as `dailyco_requests_total{method, endpoint, outcome}`.
+///
+/// `outcome` is `success`, `rate_limited` or `error`, the latter including requests
+/// which failed before `Daily` responded.
+pub(crate) fn record(
+    base_url: &Url,
+    method: &Method,
+    url: &Url,
+    res: &Result<Response, reqwest::Error>,
+) {
+    let outcome = match res {
+        // A `304 Not Modified` answers a conditional request successfully
+        Ok(resp) if resp.status() == reqwest::StatusCode::NOT_MODIFIED => "success",
+        Ok(resp) if resp.status().is_success() => "success",
+        Ok(resp) if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => "rate_limited",
+        _ => "error",
+    };
+    metrics::counter!(
+        REQUESTS_TOTAL,
+        "method" => method.to_string(),
+        "endpoint" => endpoint(base_url, url),
+        "outcome" => outcome,
+    )
+    .increment(1);
+}
+
+/// The endpoint of `url`, with the name or id it is for replaced to keep the number
+/// of labels bounded, e.g. `rooms/:id/presence` for `rooms/a-room/presence`.
+fn endpoint(base_url: &Url, url: &Url) -> String {
+    let path = base_url
+        .make_relative(url)
+        .unwrap_or_else(|| url.path().to_string());
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let mut endpoint = segments.next().unwrap_or_default().to_string();
+    if segments.next().is_some() {
+        endpoint.push_str("/:id");
+    }
+    for segment in segments {
+        endpoint.push('/');
+        endpoint.push_str(segment);
+    }
+    endpoint
+}
@@ -2,10 +2,16 @@
 #![deny(missing_debug_implementations)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 //! This crate provides Rust bindings to interact with the [`Daily` API](https://docs.daily.co/reference/rest-api).
+pub mod api;
 mod client;
 pub mod configuration;
 mod error;
+#[cfg(feature = "metrics")]
+mod instrumentation;
+pub mod logs;
 pub mod meeting_token;
+pub mod pagination;
+pub mod presence;
 pub mod room;
 mod room_properties;
 
@@ -15,10 +21,13 @@ mod self_sign_token;
 pub mod recording;
 mod utils;
 
-pub use room_properties::{RoomProperties, RoomPropertiesBuilder};
+pub use room_properties::{
+    OwnedRoomProperties, PropertyDiff, RoomProperties, RoomPropertiesBuilder,
+};
 
-pub use self::client::Client;
-pub use self::error::{DailyCoErrorInfo, DailyCoErrorKind, Error, Result};
+pub use self::api::DailyApi;
+pub use self::client::{Client, ClientBuilder, RequestOptions, WithRequestId};
+pub use self::error::{DailyCoErrorInfo, DailyCoErrorKind, Error, InvalidRequestDetail, Result};
 
 #[cfg(doctest)]
 doc_comment::doctest!("../README.md", readme);
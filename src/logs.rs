@@ -0,0 +1,78 @@
+//! Logs and quality metrics of `Daily` meetings, for diagnosing problems in a call.
+//!
+//! Only the most useful fields are typed, everything else `Daily` returns is kept in
+//! the `extra` of each entry.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// The logs and metrics of a meeting session, as described in
+/// <https://docs.daily.co/reference/rest-api/logs>
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct MeetingLogs {
+    /// Log lines reported by the participants' clients.
+    #[serde(default)]
+    pub logs: Vec<LogEntry>,
+    /// Connection quality samples reported by the participants' clients.
+    #[serde(default)]
+    pub metrics: Vec<QualityMetrics>,
+}
+
+/// A log line reported by a participant's client.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    /// When the line was logged, in ISO 8601 format, according to the server.
+    pub time: Option<String>,
+    /// When the line was logged, according to the participant's client.
+    pub client_time: Option<String>,
+    /// The meeting session the line is from.
+    pub mtg_session_id: Option<Uuid>,
+    /// The session of the participant who logged the line.
+    pub user_session_id: Option<Uuid>,
+    /// The severity of the line.
+    pub level: Option<i64>,
+    /// The logged message.
+    pub message: Option<String>,
+    /// Fields returned by `Daily` which aren't modelled by this struct.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A sample of a participant's connection quality.
+///
+/// Packet loss is a fraction, between 0 and 1, of the packets sent or received since
+/// the previous sample.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityMetrics {
+    /// When the sample was taken, according to the participant's client.
+    pub client_time: Option<String>,
+    /// The meeting session the sample is from.
+    pub mtg_session_id: Option<Uuid>,
+    /// The session of the participant the sample is for.
+    pub user_session_id: Option<Uuid>,
+    /// The round trip time to the server, in seconds.
+    pub round_trip_time: Option<f64>,
+    /// The estimated bandwidth available for sending, in bits per second.
+    pub available_outgoing_bitrate: Option<f64>,
+    /// Video received, in bits per second.
+    pub video_recv_bits_per_second: Option<f64>,
+    /// Video sent, in bits per second.
+    pub video_send_bits_per_second: Option<f64>,
+    /// Packet loss of the video received.
+    pub video_recv_packet_loss: Option<f64>,
+    /// Packet loss of the video sent.
+    pub video_send_packet_loss: Option<f64>,
+    /// Audio received, in bits per second.
+    pub audio_recv_bits_per_second: Option<f64>,
+    /// Audio sent, in bits per second.
+    pub audio_send_bits_per_second: Option<f64>,
+    /// Packet loss of the audio received.
+    pub audio_recv_packet_loss: Option<f64>,
+    /// Packet loss of the audio sent.
+    pub audio_send_packet_loss: Option<f64>,
+    /// Fields returned by `Daily` which aren't modelled by this struct.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -1,14 +1,75 @@
 //! Definition and creation of `Daily` meeting tokens.
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[cfg(feature = "self-signed-tokens")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+pub use jsonwebtoken::{Algorithm, Header};
 use serde::{Deserialize, Serialize};
 
 use crate::client::parse_dailyco_response;
 use crate::configuration::{DailyLang, RecordingType};
-use crate::utils::default_as_true;
+use crate::utils::{default_as_true, timestamp_in, validate_timing, whole_secs};
 use crate::Client;
 
+/// A meeting token, which can be used to join a `Daily` room.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct MeetingTokenString(String);
+
+impl MeetingTokenString {
+    /// The token as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Convert the token into the underlying `String`.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for MeetingTokenString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for MeetingTokenString {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl AsRef<str> for MeetingTokenString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for MeetingTokenString {
+    fn from(token: String) -> Self {
+        Self(token)
+    }
+}
+
+impl From<MeetingTokenString> for String {
+    fn from(token: MeetingTokenString) -> Self {
+        token.0
+    }
+}
+
 /// A `CreateMeetingToken` can be used to create a `Daily` meeting token for gaining
 /// access to a private room.
+///
+/// Like [RoomPropertiesBuilder](crate::RoomPropertiesBuilder), its setters take and
+/// return the builder by value.
 #[derive(Debug, Copy, Clone, Serialize, Default)]
+#[must_use]
 pub struct CreateMeetingToken<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) room_name: Option<&'a str>,
@@ -50,125 +111,166 @@ pub struct CreateMeetingToken<'a> {
 
 impl<'a> CreateMeetingToken<'a> {
     /// Constructs a new `CreateMeetingToken`.
-    #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
     /// The room for which this token is valid. If `room_name` isn't set, the token is
     /// valid for all rooms in your domain.
-    pub fn room_name(&mut self, room_name: &'a str) -> &mut Self {
+    pub fn room_name(mut self, room_name: &'a str) -> Self {
         self.room_name = Some(room_name);
         self
     }
 
     /// Kick this user out of the meeting at the time this meeting token expires.
-    pub fn eject_at_token_exp(&mut self, eject_at_token_exp: bool) -> &mut Self {
+    pub fn eject_at_token_exp(mut self, eject_at_token_exp: bool) -> Self {
         self.eject_at_token_exp = Some(eject_at_token_exp);
         self
     }
 
     /// Kick this user out of the meeting this many seconds after they join the meeting.
-    pub fn eject_after_elapsed(&mut self, eject_after_elapsed: i64) -> &mut Self {
+    pub fn eject_after_elapsed(mut self, eject_after_elapsed: i64) -> Self {
         self.eject_after_elapsed = Some(eject_after_elapsed);
         self
     }
 
+    /// Kick this user out of the meeting `duration` after they join the meeting, see
+    /// [`eject_after_elapsed`](Self::eject_after_elapsed). A partial second is rounded
+    /// up to a whole one.
+    pub fn eject_after(self, duration: Duration) -> Self {
+        self.eject_after_elapsed(whole_secs(duration))
+    }
+
     /// UTC timestamp before which the token cannot be used.
-    pub fn nbf(&mut self, nbf: i64) -> &mut Self {
+    pub fn nbf(mut self, nbf: i64) -> Self {
         self.nbf = Some(nbf);
         self
     }
 
     /// UTC timestamp for expiration of the token.
-    pub fn exp(&mut self, exp: i64) -> &mut Self {
+    pub fn exp(mut self, exp: i64) -> Self {
         self.exp = Some(exp);
         self
     }
 
+    /// The token cannot be used until `duration` from now. Sets `nbf` relative to
+    /// the current time.
+    pub fn not_before_in(self, duration: Duration) -> Self {
+        self.nbf(timestamp_in(duration))
+    }
+
+    /// The token expires `duration` from now. Sets `exp` relative to the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use dailyco::meeting_token::CreateMeetingToken;
+    /// let token = CreateMeetingToken::new()
+    ///     .room_name("a-room")
+    ///     .expires_in(Duration::from_secs(3 * 60 * 60));
+    /// ```
+    pub fn expires_in(self, duration: Duration) -> Self {
+        self.exp(timestamp_in(duration))
+    }
+
     /// The user has meeting owner privileges.
-    pub fn is_owner(&mut self, is_owner: bool) -> &mut Self {
+    pub fn is_owner(mut self, is_owner: bool) -> Self {
         self.is_owner = Some(is_owner);
         self
     }
 
     /// The user's name in this meeting.
-    pub fn user_name(&mut self, user_name: &'a str) -> &mut Self {
+    pub fn user_name(mut self, user_name: &'a str) -> Self {
         self.user_name = Some(user_name);
         self
     }
 
     /// The user's id for this meeting session.
-    pub fn user_id(&mut self, user_id: &'a str) -> &mut Self {
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
         self.user_id = Some(user_id);
         self
     }
 
     /// The user is allowed to screenshare.
-    pub fn enable_screenshare(&mut self, enable_screenshare: bool) -> &mut Self {
+    pub fn enable_screenshare(mut self, enable_screenshare: bool) -> Self {
         self.enable_screenshare = Some(enable_screenshare);
         self
     }
 
     /// When a participant first joins a meeting, keep their camera off.
-    pub fn start_video_off(&mut self, start_video_off: bool) -> &mut Self {
+    pub fn start_video_off(mut self, start_video_off: bool) -> Self {
         self.start_video_off = Some(start_video_off);
         self
     }
 
     /// When a participant first joins a meeting, keep their microphone muted.
-    pub fn start_audio_off(&mut self, start_audio_off: bool) -> &mut Self {
+    pub fn start_audio_off(mut self, start_audio_off: bool) -> Self {
         self.start_audio_off = Some(start_audio_off);
         self
     }
 
     /// Allowed recording type
-    pub fn enable_recording(&mut self, enable_recording: RecordingType) -> &mut Self {
+    pub fn enable_recording(mut self, enable_recording: RecordingType) -> Self {
         self.enable_recording = Some(enable_recording);
         self
     }
 
     /// Determines whether participant enters a waiting room with a camera, mic, and
     /// browser check before joining a call.
-    pub fn enable_prejoin_ui(&mut self, enable_prejoin_ui: bool) -> &mut Self {
+    pub fn enable_prejoin_ui(mut self, enable_prejoin_ui: bool) -> Self {
         self.enable_prejoin_ui = Some(enable_prejoin_ui);
         self
     }
 
     /// Reduces the volume of log messages. This feature should be enabled when there
-    /// are more than 300 participants in a meeting to help improve performance.
-    pub fn enable_terse_logging(&mut self, enable_terse_logging: bool) -> &mut Self {
+    /// are more than 200 participants in a meeting to help improve performance.
+    pub fn enable_terse_logging(mut self, enable_terse_logging: bool) -> Self {
         self.enable_terse_logging = Some(enable_terse_logging);
         self
     }
 
     /// Start cloud recording when the user joins the room. This can be used to always record and
     /// archive meetings, for example in a customer support context.
-    pub fn start_cloud_recording(&mut self, start_cloud_recording: bool) -> &mut Self {
+    pub fn start_cloud_recording(mut self, start_cloud_recording: bool) -> Self {
         self.start_cloud_recording = Some(start_cloud_recording);
         self
     }
 
     /// When a user leaves a meeting using the button in the in-call menu bar,
     /// the browser tab closes.
-    pub fn close_tab_on_exit(&mut self, close_tab_on_exit: bool) -> &mut Self {
+    pub fn close_tab_on_exit(mut self, close_tab_on_exit: bool) -> Self {
         self.close_tab_on_exit = Some(close_tab_on_exit);
         self
     }
 
     /// When a user leaves a meeting using the button in the in-call menu bar,
     /// the browser loads this URL.
-    pub fn redirect_on_meeting_exit(&mut self, redirect_on_meeting_exit: &'a str) -> &mut Self {
+    pub fn redirect_on_meeting_exit(mut self, redirect_on_meeting_exit: &'a str) -> Self {
         self.redirect_on_meeting_exit = Some(redirect_on_meeting_exit);
         self
     }
 
     /// The default language of the Daily prebuilt video call UI, for this room.
-    pub fn lang(&mut self, lang: DailyLang) -> &mut Self {
+    pub fn lang(mut self, lang: DailyLang) -> Self {
         self.lang = Some(lang);
         self
     }
 
+    /// Check for settings which contradict each other: `nbf` not before `exp` or a
+    /// negative `eject_after_elapsed`. Other values are left for `Daily` to validate.
+    ///
+    /// This is called by [send](#method.send). Self-signed tokens aren't checked by
+    /// `Daily` until they are used, so call this before [self_sign](#method.self_sign)
+    /// to catch these mistakes early.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`](crate::Error::InvalidConfig) describing the first problem found.
+    pub fn validate(&self) -> crate::Result<()> {
+        validate_timing(self.nbf, self.exp, self.eject_after_elapsed)
+    }
+
     /// Make the request to create the custom `Daily` meeting token for joining a room.
     ///
     /// # Examples
@@ -178,7 +280,8 @@ impl<'a> CreateMeetingToken<'a> {
     /// ```no_run
     /// # use dailyco::{Client, Result};
     /// # use dailyco::meeting_token::CreateMeetingToken;
-    /// # async fn run() -> Result<String> {
+    /// # use dailyco::meeting_token::MeetingTokenString;
+    /// # async fn run() -> Result<MeetingTokenString> {
     /// let client = Client::new("test-api-key")?;
     /// let token = CreateMeetingToken::new()
     ///     .room_name("room-user-should-own")
@@ -188,12 +291,13 @@ impl<'a> CreateMeetingToken<'a> {
     /// # Ok(token)
     /// # }
     /// ```
-    pub async fn send(&self, client: &Client) -> crate::Result<String> {
+    pub async fn send(&self, client: &Client) -> crate::Result<MeetingTokenString> {
+        self.validate()?;
         #[derive(Deserialize)]
         /// Response from Daily for successful meeting token creation
         struct MeetingTokenResponse {
             /// The token created
-            token: String,
+            token: MeetingTokenString,
         }
 
         #[derive(Serialize)]
@@ -204,13 +308,45 @@ impl<'a> CreateMeetingToken<'a> {
         // This should not be able to fail
         let token_url = client.base_url.join("meeting-tokens/").unwrap();
         let body = MeetingTokenBody { properties: self };
-        let resp = client.client.post(token_url).json(&body).send().await?;
+        let resp = client.execute(client.post_json(token_url, &body)).await?;
 
         parse_dailyco_response(resp)
             .await
             .map(|token_resp: MeetingTokenResponse| token_resp.token)
     }
 
+    /// Like [send](Self::send), but also returning the claims of the created token,
+    /// e.g. to store alongside it.
+    ///
+    /// The claims are converted from this builder rather than read back from `Daily`,
+    /// so they show what was requested. Use
+    /// [Client::get_meeting_token](crate::Client::get_meeting_token) to see what
+    /// `Daily` made of the token instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::meeting_token::CreateMeetingToken;
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let (token, claims) = CreateMeetingToken::new()
+    ///     .room_name("a-room")
+    ///     .expires_in(std::time::Duration::from_secs(60 * 60))
+    ///     .send_with_claims(&client)
+    ///     .await?;
+    /// println!("{} is valid until {:?}", token.as_str(), claims.exp);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_claims(
+        &self,
+        client: &Client,
+    ) -> crate::Result<(MeetingTokenString, MeetingToken)> {
+        let token = self.send(client).await?;
+        Ok((token, MeetingToken::from(*self)))
+    }
+
     #[cfg(feature = "self-signed-tokens")]
     #[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
     /// Self-sign a `Daily` meeting token corresponding to this configuration,
@@ -225,8 +361,8 @@ impl<'a> CreateMeetingToken<'a> {
     /// Create a token to join a room with owner privileges.
     ///
     /// ```no_run
-    /// # use dailyco::meeting_token::CreateMeetingToken;
-    /// # fn run() -> String {
+    /// # use dailyco::meeting_token::{CreateMeetingToken, MeetingTokenString};
+    /// # fn run() -> MeetingTokenString {
     /// let token = CreateMeetingToken::new()
     ///     .room_name("room-user-should-own")
     ///     .is_owner(true)
@@ -234,14 +370,132 @@ impl<'a> CreateMeetingToken<'a> {
     /// # token
     /// # }
     /// ```
-    pub fn self_sign(&self, domain_id: &str, secret_key: &str) -> String {
-        crate::self_sign_token::self_sign_token(*self, domain_id, secret_key)
+    pub fn self_sign(&self, domain_id: &str, secret_key: &str) -> MeetingTokenString {
+        crate::self_sign_token::self_sign_token(*self, domain_id, secret_key).into()
+    }
+
+    #[cfg(feature = "self-signed-tokens")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+    /// Self-sign a `Daily` meeting token like [self_sign](#method.self_sign), with a custom
+    /// JWT header.
+    ///
+    /// `Daily` verifies self-signed tokens with the domain's API key, so `header` must use
+    /// an HMAC algorithm. [self_sign](#method.self_sign) uses HS256 without a `kid`, which
+    /// is what `Daily` documents, so this is only needed for advanced use.
+    ///
+    /// # Optional
+    ///
+    /// This requires the optional `self-signed-tokens` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`](crate::Error::InvalidConfig) if [validate](#method.validate)
+    /// fails, and [`Error::InvalidMeetingToken`](crate::Error::InvalidMeetingToken) if the
+    /// token can't be signed with the header, e.g. because its algorithm is not HMAC based.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::meeting_token::{Algorithm, CreateMeetingToken, Header};
+    /// # fn run() -> dailyco::Result<()> {
+    /// let token = CreateMeetingToken::new()
+    ///     .room_name("a-room")
+    ///     .self_sign_with_header("domain_id", "test-api-key", &Header::new(Algorithm::HS512))?;
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn self_sign_with_header(
+        &self,
+        domain_id: &str,
+        secret_key: &str,
+        header: &Header,
+    ) -> crate::Result<MeetingTokenString> {
+        self.validate()?;
+        crate::self_sign_token::self_sign_token_with_header(*self, domain_id, secret_key, header)
+            .map(MeetingTokenString::from)
     }
 }
 
+/// Verify and decode a self-signed meeting token, trying each of the `secret_keys`
+/// in turn until one of them verifies its signature.
+///
+/// Passing both the current and previous secret allows rotating the secret without
+/// invalidating tokens signed shortly before the rotation.
+///
+/// # Optional
+///
+/// This requires the optional `self-signed-tokens` feature enabled.
+///
+/// # Errors
+///
+/// [`Error::MeetingTokenKeyMismatch`](crate::Error::MeetingTokenKeyMismatch) if none of
+/// the keys verify the token, and [`Error::InvalidMeetingToken`](crate::Error::InvalidMeetingToken)
+/// if the token is malformed, past its `exp` or before its `nbf`.
+///
+/// `exp` and `nbf` are checked with a leeway of [DEFAULT_LEEWAY], to tolerate the
+/// clock of the machine which signed the token being slightly off. Use
+/// [decode_with_keys_and_leeway] to choose another leeway.
+///
+/// # Examples
+///
+/// ```
+/// # use dailyco::meeting_token::{decode_with_keys, CreateMeetingToken};
+/// # fn run() -> dailyco::Result<()> {
+/// let token = CreateMeetingToken::new()
+///     .room_name("a-room")
+///     .self_sign("domain_id", "old-secret");
+/// let decoded = decode_with_keys(token.as_str(), &["new-secret", "old-secret"])?;
+/// assert_eq!(decoded.room_name.as_deref(), Some("a-room"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "self-signed-tokens")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+pub fn decode_with_keys(token: &str, secret_keys: &[&str]) -> crate::Result<MeetingToken> {
+    decode_with_keys_and_leeway(token, secret_keys, DEFAULT_LEEWAY)
+}
+
+/// The clock skew tolerated by [decode_with_keys] when checking a token's `exp` and `nbf`.
+#[cfg(feature = "self-signed-tokens")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+pub const DEFAULT_LEEWAY: Duration = Duration::from_secs(60);
+
+/// Like [decode_with_keys], but accepting a token up to `leeway` after its `exp`
+/// or before its `nbf`. Partial seconds are rounded up.
+///
+/// This requires the optional `self-signed-tokens` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use dailyco::meeting_token::{decode_with_keys_and_leeway, CreateMeetingToken};
+/// # fn run() -> dailyco::Result<()> {
+/// let token = CreateMeetingToken::new()
+///     .room_name("a-room")
+///     .self_sign("domain_id", "secret");
+/// // Only tolerate clocks being up to 5 seconds apart
+/// let decoded = decode_with_keys_and_leeway(token.as_str(), &["secret"], Duration::from_secs(5))?;
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "self-signed-tokens")]
+#[cfg_attr(docsrs, doc(cfg(feature = "self-signed-tokens")))]
+pub fn decode_with_keys_and_leeway(
+    token: &str,
+    secret_keys: &[&str],
+    leeway: Duration,
+) -> crate::Result<MeetingToken> {
+    crate::self_sign_token::decode_with_keys(token, secret_keys, leeway)
+}
+
 /// A `MeetingToken` describes the configuration of a meeting token used to join a
 /// `Daily` private meeting room.
-#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "strict-deserialize", serde(deny_unknown_fields))]
 pub struct MeetingToken {
     /// The room for which this token is valid. If `room_name` isn't set, the token is
     /// valid for all rooms in your domain.
@@ -277,7 +531,7 @@ pub struct MeetingToken {
     /// browser check before joining a call.
     pub enable_prejoin_ui: Option<bool>,
     /// Reduces the volume of log messages. This feature should be enabled when there
-    /// are more than 300 participants in a meeting to help improve performance.
+    /// are more than 200 participants in a meeting to help improve performance.
     #[serde(default)]
     pub enable_terse_logging: bool,
     /// Start cloud recording when the user joins the room. This can be used to always record and
@@ -301,25 +555,116 @@ fn option_str_to_string(str: Option<&str>) -> Option<String> {
 
 impl From<CreateMeetingToken<'_>> for MeetingToken {
     fn from(builder: CreateMeetingToken) -> Self {
+        // Destructured so that adding a field to the builder fails to compile until
+        // it is also handled here
+        let CreateMeetingToken {
+            room_name,
+            eject_at_token_exp,
+            eject_after_elapsed,
+            nbf,
+            exp,
+            is_owner,
+            user_name,
+            user_id,
+            enable_screenshare,
+            start_video_off,
+            start_audio_off,
+            enable_recording,
+            enable_prejoin_ui,
+            enable_terse_logging,
+            start_cloud_recording,
+            close_tab_on_exit,
+            redirect_on_meeting_exit,
+            lang,
+        } = builder;
         Self {
-            room_name: option_str_to_string(builder.room_name),
-            eject_at_token_exp: builder.eject_at_token_exp.unwrap_or_default(),
-            eject_after_elapsed: builder.eject_after_elapsed,
-            nbf: builder.nbf,
-            exp: builder.exp,
-            is_owner: builder.is_owner.unwrap_or_default(),
-            user_name: option_str_to_string(builder.user_name),
-            user_id: option_str_to_string(builder.user_id),
-            enable_screenshare: builder.enable_screenshare.unwrap_or(true),
-            start_video_off: builder.start_video_off.unwrap_or_default(),
-            start_audio_off: builder.start_audio_off.unwrap_or_default(),
-            enable_recording: builder.enable_recording,
-            enable_prejoin_ui: builder.enable_prejoin_ui,
-            enable_terse_logging: builder.enable_terse_logging.unwrap_or_default(),
-            start_cloud_recording: builder.start_cloud_recording.unwrap_or_default(),
-            close_tab_on_exit: builder.close_tab_on_exit.unwrap_or_default(),
-            redirect_on_meeting_exit: option_str_to_string(builder.redirect_on_meeting_exit),
-            lang: builder.lang,
+            room_name: option_str_to_string(room_name),
+            eject_at_token_exp: eject_at_token_exp.unwrap_or_default(),
+            eject_after_elapsed,
+            nbf,
+            exp,
+            is_owner: is_owner.unwrap_or_default(),
+            user_name: option_str_to_string(user_name),
+            user_id: option_str_to_string(user_id),
+            enable_screenshare: enable_screenshare.unwrap_or(true),
+            start_video_off: start_video_off.unwrap_or_default(),
+            start_audio_off: start_audio_off.unwrap_or_default(),
+            enable_recording,
+            enable_prejoin_ui,
+            enable_terse_logging: enable_terse_logging.unwrap_or_default(),
+            start_cloud_recording: start_cloud_recording.unwrap_or_default(),
+            close_tab_on_exit: close_tab_on_exit.unwrap_or_default(),
+            redirect_on_meeting_exit: option_str_to_string(redirect_on_meeting_exit),
+            lang,
         }
     }
 }
+
+/// The permissions of a participant in a meeting, in the shape of the `permissions`
+/// a meeting token grants.
+///
+/// Meeting tokens can't be changed once issued, so these are how a participant's
+/// permissions are changed during a meeting instead, with
+/// [Client::update_participant](crate::Client::update_participant). Permissions left
+/// as `None` are unchanged.
+///
+/// A room's default permissions, set with
+/// [RoomPropertiesBuilder::permissions](crate::RoomPropertiesBuilder::permissions),
+/// take the same shape.
+///
+/// <https://docs.daily.co/reference/rest-api/rooms/update-permissions>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPermissions {
+    /// Whether the participant appears to others in the meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_presence: Option<bool>,
+    /// Which kinds of media the participant may send.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send: Option<Allowed<MediaKind>>,
+    /// Which parts of the meeting the participant may administer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_admin: Option<Allowed<AdminKind>>,
+}
+
+/// Either all or none of a kind of permission, or only some of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Allowed<T> {
+    /// `true` to allow everything, `false` to allow nothing.
+    All(bool),
+    /// Allow only these.
+    Only(Vec<T>),
+}
+
+/// A kind of media a participant can be allowed to send.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum MediaKind {
+    /// Camera video.
+    Video,
+    /// Microphone audio.
+    Audio,
+    /// Screen share video.
+    ScreenVideo,
+    /// Screen share audio.
+    ScreenAudio,
+    /// Custom video tracks.
+    CustomVideo,
+    /// Custom audio tracks.
+    CustomAudio,
+}
+
+/// A part of the meeting a participant can be allowed to administer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AdminKind {
+    /// Other participants, e.g. changing their permissions or ejecting them.
+    Participants,
+    /// Live streaming.
+    Streaming,
+    /// Transcription.
+    Transcription,
+}
@@ -0,0 +1,138 @@
+//! Types shared by the paginated `Daily` list endpoints.
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::recording::{RecordingId, RecordingObject};
+use crate::room::Room;
+
+/// The page size `Daily` uses for its list endpoints when no `limit` is given.
+pub(crate) const DEFAULT_LIST_LIMIT: u32 = 100;
+
+/// An opaque position in a paginated list, used as the `starting_after` or
+/// `ending_before` of a list request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// The cursor as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(cursor: String) -> Self {
+        Self(cursor)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(cursor: &str) -> Self {
+        Self(cursor.to_string())
+    }
+}
+
+impl From<Uuid> for Cursor {
+    fn from(id: Uuid) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<RecordingId> for Cursor {
+    fn from(id: RecordingId) -> Self {
+        Self(id.to_string())
+    }
+}
+
+/// An item of a paginated list, which can be used as the cursor to page from.
+pub trait HasCursor {
+    /// The cursor positioned at this item.
+    fn cursor(&self) -> Cursor;
+}
+
+impl HasCursor for RecordingObject {
+    fn cursor(&self) -> Cursor {
+        self.id.into()
+    }
+}
+
+impl HasCursor for Room {
+    fn cursor(&self) -> Cursor {
+        self.id.as_str().into()
+    }
+}
+
+/// A single page of results from a paginated list endpoint.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    data: Vec<T>,
+    total_count: usize,
+    next_cursor: Option<Cursor>,
+    prev_cursor: Option<Cursor>,
+}
+
+impl<T: HasCursor> Page<T> {
+    /// `limit` is the page size requested, and `from_cursor` whether the page was
+    /// requested relative to a cursor rather than from the start of the list.
+    pub(crate) fn new(data: Vec<T>, total_count: usize, limit: u32, from_cursor: bool) -> Self {
+        // A page shorter than requested is the last one
+        let next_cursor = data
+            .last()
+            .filter(|_| data.len() >= limit as usize)
+            .map(HasCursor::cursor);
+        let prev_cursor = data.first().filter(|_| from_cursor).map(HasCursor::cursor);
+        Self {
+            data,
+            total_count,
+            next_cursor,
+            prev_cursor,
+        }
+    }
+}
+
+impl<T> Page<T> {
+    /// The items on this page.
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Take the items on this page.
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// The total number of items in the list, across all pages.
+    pub fn total_count(&self) -> usize {
+        self.total_count
+    }
+
+    /// The cursor to pass as `starting_after` to request the following page, or `None`
+    /// if this page was shorter than requested and so is the last one.
+    pub fn next_cursor(&self) -> Option<&Cursor> {
+        self.next_cursor.as_ref()
+    }
+
+    /// The cursor to pass as `ending_before` to request the preceding page, or `None`
+    /// if this page was requested from the start of the list.
+    pub fn prev_cursor(&self) -> Option<&Cursor> {
+        self.prev_cursor.as_ref()
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
@@ -0,0 +1,43 @@
+//! The participants present in `Daily` meetings, now and in past sessions.
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A participant currently in a meeting, as described in
+/// <https://docs.daily.co/reference/rest-api/rooms/get-room-presence>
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-deserialize", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PresenceParticipant {
+    /// The name of the room the participant is in.
+    pub room: String,
+    /// The participant's session id, which identifies them in the meeting, e.g. for
+    /// [Client::update_participant](crate::Client::update_participant).
+    pub id: Uuid,
+    /// The user id set by the participant's meeting token, if any.
+    pub user_id: Option<String>,
+    /// The participant's name, if they set one.
+    pub user_name: Option<String>,
+    /// When the participant joined, in ISO 8601 format.
+    pub join_time: String,
+    /// How many seconds the participant has been in the meeting.
+    pub duration: u64,
+}
+
+/// A participant's session in a room, current or past, as listed by
+/// [Client::list_sessions](crate::Client::list_sessions).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Session {
+    /// The participant's session id, unique to each time they joined.
+    pub session_id: Uuid,
+    /// The user id set by the participant's meeting token, if any.
+    pub user_id: Option<String>,
+    /// The participant's name, if they set one.
+    pub user_name: Option<String>,
+    /// When the participant joined, as a unix timestamp.
+    pub join_time: i64,
+    /// How many seconds the participant was in the meeting, or has been so far if the
+    /// session is active.
+    pub duration: u64,
+    /// Whether the participant is still in the meeting.
+    pub active: bool,
+}
@@ -1,17 +1,72 @@
 //! Functionality related to `Daily` recordings.
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures_util::future;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::{StatusCode, Url};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::client::parse_dailyco_response;
+use crate::pagination::{Cursor, Page, DEFAULT_LIST_LIMIT};
+use crate::utils::timestamp_in;
 use crate::Client;
 
+/// The unique id of a recording.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct RecordingId(Uuid);
+
+impl RecordingId {
+    /// The id as a `Uuid`.
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl fmt::Display for RecordingId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for RecordingId {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl AsRef<Uuid> for RecordingId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<Uuid> for RecordingId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<RecordingId> for Uuid {
+    fn from(id: RecordingId) -> Self {
+        id.0
+    }
+}
+
 /// The recording object, representing a single saved recording, as described
 /// in <https://docs.daily.co/reference/rest-api/recordings/config>
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "strict-deserialize", serde(deny_unknown_fields))]
 pub struct RecordingObject {
     /// A unique, opaque ID for this object. You can use this ID in API calls,
     /// and in paginated list operations.
-    pub id: Uuid,
+    pub id: RecordingId,
     /// The name of the room.
     pub room_name: String,
     /// When the recording started. This is a unix timestamp (seconds since the epoch).
@@ -30,9 +85,31 @@ pub struct RecordingObject {
     pub meeting_session_id: Uuid,
 }
 
+impl RecordingObject {
+    /// When the recording ended, as a unix timestamp: its `start_ts` plus its
+    /// `duration`. `None` while the recording is in progress, since `Daily` doesn't
+    /// return a `duration` until then.
+    pub fn end_ts(&self) -> Option<i64> {
+        self.duration
+            .map(|duration| self.start_ts + i64::from(duration))
+    }
+
+    /// When the recording ended, see [end_ts](Self::end_ts).
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn end_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.end_ts()?, 0)
+    }
+}
+
 /// The status of a recording.
+///
+/// Statuses this crate doesn't know about, like transient ones `Daily` reports
+/// without documenting them, deserialize as [`Unknown`](Self::Unknown) rather than
+/// failing to deserialize the whole recording.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
 pub enum RecordingStatus {
     /// Finished
     Finished,
@@ -40,6 +117,36 @@ pub enum RecordingStatus {
     InProgress,
     /// Canceled
     Canceled,
+    /// Failed, e.g. because the recording couldn't be uploaded.
+    Failed,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl RecordingStatus {
+    /// Whether the recording has stopped and its status won't change again,
+    /// i.e. it is `Finished`, `Canceled` or `Failed`. An `Unknown` status is assumed
+    /// to still change.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            Self::Finished | Self::Canceled | Self::Failed => true,
+            Self::InProgress | Self::Unknown => false,
+        }
+    }
+}
+
+impl fmt::Display for RecordingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let disp = match self {
+            Self::Finished => "finished",
+            Self::InProgress => "in-progress",
+            Self::Canceled => "canceled",
+            Self::Failed => "failed",
+            Self::Unknown => "unknown",
+        };
+        f.write_str(disp)
+    }
 }
 
 /// A builder for the `/recordings/:id/access-link` request, which creates and returns
@@ -53,7 +160,7 @@ pub struct GetRecordingAccessLink {
 }
 
 /// Access link for a recording, as described in <https://docs.daily.co/reference/rest-api/recordings/get-recording-link>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RecordingAccessLink {
     /// The download_link is a cryptographically signed, time-limited,
     /// direct link to a .mp4 file stored on Amazon S3
@@ -63,6 +170,63 @@ pub struct RecordingAccessLink {
     pub expires: i64,
 }
 
+impl RecordingAccessLink {
+    /// Whether the link is past `expires`, so requesting it would be rejected and a
+    /// new one is needed.
+    pub fn is_expired(&self) -> bool {
+        self.expires <= timestamp_in(Duration::ZERO)
+    }
+
+    /// When the link expires, see [expires](Self::expires).
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn expires_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.expires, 0)
+    }
+
+    /// The `Content-Disposition` S3 will serve the recording with, if the link was
+    /// signed with one, e.g. `attachment; filename="recording.mp4"`.
+    pub fn content_disposition(&self) -> Option<String> {
+        let link = Url::parse(&self.download_link).ok()?;
+        let disposition = link
+            .query_pairs()
+            .find(|(key, _)| key == "response-content-disposition")
+            .map(|(_, value)| value.into_owned());
+        disposition
+    }
+
+    /// Whether the recording is served inline, so it can be played directly from
+    /// the link, rather than as an attachment the browser downloads.
+    ///
+    /// `Daily` serves recordings as attachments unless they are stored in a
+    /// [RecordingsBucket](crate::configuration::RecordingsBucket) with
+    /// `allow_streaming_from_bucket` set. A link without a `Content-Disposition` is
+    /// served inline.
+    pub fn is_streamable(&self) -> bool {
+        self.content_disposition().map_or(true, |disposition| {
+            !disposition
+                .trim_start()
+                .to_ascii_lowercase()
+                .starts_with("attachment")
+        })
+    }
+
+    /// Download the recording this link points to, returning the whole .mp4 file.
+    ///
+    /// The link is requested without the `Daily` API key. If S3 rejects the link,
+    /// typically because it is past `expires`, [`Error::RecordingLinkExpired`](crate::Error::RecordingLinkExpired)
+    /// is returned.
+    pub async fn download_bytes(&self, client: &Client) -> crate::Result<Bytes> {
+        let resp = client.client.get(&self.download_link).send().await?;
+        if resp.status() == StatusCode::FORBIDDEN {
+            return Err(crate::Error::RecordingLinkExpired {
+                expires: self.expires,
+            });
+        }
+        Ok(resp.error_for_status()?.bytes().await?)
+    }
+}
+
 impl GetRecordingAccessLink {
     /// Constructs a new `GetRecordingAccessLink`.
     #[must_use]
@@ -77,9 +241,14 @@ impl GetRecordingAccessLink {
     }
 
     /// Send the request to create and get an access link for a recording.
-    pub async fn send(&self, client: &Client, id: Uuid) -> crate::Result<RecordingAccessLink> {
+    pub async fn send(
+        &self,
+        client: &Client,
+        id: impl Into<RecordingId>,
+    ) -> crate::Result<RecordingAccessLink> {
+        let id = id.into();
         let url = format!("{}/recordings/{id}/access-link", client.base_url);
-        let resp = client.client.get(url).query(self).send().await?;
+        let resp = client.execute(client.get(url).query(self)).await?;
         parse_dailyco_response(resp).await
     }
 }
@@ -97,11 +266,11 @@ pub struct ListedRecordings {
 ///
 /// Recordings are returned sorted by created_at time in reverse chronological order.
 /// This endpoint is detailed in <https://docs.daily.co/reference/rest-api/recordings/list-recordings>
-#[derive(Debug, Copy, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct ListRecordings<'a> {
     limit: Option<u32>,
-    ending_before: Option<Uuid>,
-    starting_after: Option<Uuid>,
+    ending_before: Option<Cursor>,
+    starting_after: Option<Cursor>,
     room_name: Option<&'a str>,
 }
 
@@ -120,15 +289,15 @@ impl<'a> ListRecordings<'a> {
     }
 
     /// The `ending_before` argument is used to fetch previous pages of results.
-    pub fn ending_before(&mut self, ending_before: Uuid) -> &mut Self {
-        self.ending_before = Some(ending_before);
+    pub fn ending_before(&mut self, ending_before: impl Into<Cursor>) -> &mut Self {
+        self.ending_before = Some(ending_before.into());
         self
     }
 
     /// The `starting_after` argument sets the starting point of the page and is used to
     /// fetch "subsequent" pages of results.
-    pub fn starting_after(&mut self, starting_after: Uuid) -> &mut Self {
-        self.starting_after = Some(starting_after);
+    pub fn starting_after(&mut self, starting_after: impl Into<Cursor>) -> &mut Self {
+        self.starting_after = Some(starting_after.into());
         self
     }
 
@@ -141,7 +310,136 @@ impl<'a> ListRecordings<'a> {
     /// Return a list of recordings.
     pub async fn send(&self, client: &Client) -> crate::Result<ListedRecordings> {
         let url = format!("{}/recordings", client.base_url);
-        let resp = client.client.get(url).query(self).send().await?;
+        let resp = client.execute(client.get(url).query(self)).await?;
         parse_dailyco_response(resp).await
     }
+
+    /// Return a single [Page] of recordings, with the cursors to request the pages
+    /// around it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::recording::ListRecordings;
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let first = ListRecordings::new().limit(20).send_page(&client).await?;
+    /// if let Some(cursor) = first.next_cursor() {
+    ///     let second = ListRecordings::new()
+    ///         .limit(20)
+    ///         .starting_after(cursor.clone())
+    ///         .send_page(&client)
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_page(&self, client: &Client) -> crate::Result<Page<RecordingObject>> {
+        let listed = self.send(client).await?;
+        let limit = self.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+        let from_cursor = self.starting_after.is_some() || self.ending_before.is_some();
+        Ok(Page::new(
+            listed.data,
+            listed.total_count as usize,
+            limit,
+            from_cursor,
+        ))
+    }
+    /// Return a stream of every recording matching this request, fetching further pages
+    /// as needed until a page shorter than `limit` is returned.
+    ///
+    /// Pages after the first are requested with `starting_after` set to the last recording
+    /// received, keeping the `limit` and `room_name` of this request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::recording::{ListRecordings, RecordingObject};
+    /// use futures_util::TryStreamExt;
+    /// # async fn run() -> Result<Vec<RecordingObject>> {
+    /// let client = Client::new("test-api-key")?;
+    /// let recordings = ListRecordings::new()
+    ///     .room_name("a-room")
+    ///     .into_stream(&client)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(recordings)
+    /// # }
+    /// ```
+    pub fn into_stream<'c>(
+        &self,
+        client: &'c Client,
+    ) -> impl Stream<Item = crate::Result<RecordingObject>> + 'c
+    where
+        'a: 'c,
+    {
+        let first_page: ListRecordings<'c> = self.clone();
+        stream::try_unfold(Some(first_page), move |request| async move {
+            let Some(request) = request else {
+                return crate::Result::Ok(None);
+            };
+            let page = request.send_page(client).await?;
+            let next_page = page.next_cursor().map(|cursor| ListRecordings {
+                ending_before: None,
+                starting_after: Some(cursor.clone()),
+                ..request
+            });
+            let recordings = page.into_iter().map(Ok);
+            Ok(Some((stream::iter(recordings), next_page)))
+        })
+        .try_flatten()
+    }
+
+    /// Return a stream of every finished recording matching this request, each with
+    /// an access link created by `link`, making up to `concurrency` access link
+    /// requests at a time.
+    ///
+    /// Recordings which aren't [finished](RecordingStatus::Finished) are skipped, as
+    /// they have nothing to download yet. Failing to create one access link yields an
+    /// error for that recording and the stream carries on, while failing to list the
+    /// recordings ends it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::recording::{GetRecordingAccessLink, ListRecordings};
+    /// use futures_util::{pin_mut, StreamExt};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let mut link = GetRecordingAccessLink::new();
+    /// link.valid_for_secs(60 * 60);
+    /// let links = ListRecordings::new()
+    ///     .room_name("a-room")
+    ///     .access_links(&client, link, 4);
+    /// pin_mut!(links);
+    /// while let Some(res) = links.next().await {
+    ///     match res {
+    ///         Ok((recording, link)) => println!("{}: {}", recording.id, link.download_link),
+    ///         Err(err) => eprintln!("{err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn access_links<'c>(
+        &self,
+        client: &'c Client,
+        link: GetRecordingAccessLink,
+        concurrency: usize,
+    ) -> impl Stream<Item = crate::Result<(RecordingObject, RecordingAccessLink)>> + 'c
+    where
+        'a: 'c,
+    {
+        self.into_stream(client)
+            .try_filter(|recording| future::ready(recording.status == RecordingStatus::Finished))
+            .map(move |recording| async move {
+                let recording = recording?;
+                let access_link = link.send(client, recording.id).await?;
+                Ok((recording, access_link))
+            })
+            .buffered(concurrency.max(1))
+    }
 }
@@ -1,38 +1,77 @@
 //! Definition and creation of `Daily` rooms.
+use std::collections::HashSet;
+use std::time::Duration;
+
+use futures_util::future;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use crate::client::parse_dailyco_response;
-use crate::room_properties::{RoomProperties, RoomPropertiesBuilder};
-use crate::Client;
+use crate::meeting_token::MeetingTokenString;
+use crate::pagination::{Cursor, Page, DEFAULT_LIST_LIMIT};
+use crate::room_properties::{OwnedRoomProperties, RoomProperties, RoomPropertiesBuilder};
+use crate::utils::matches_requested;
+use crate::{Client, DailyCoErrorKind, Error, InvalidRequestDetail};
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 /// Possibilities for video room visibility.
 pub enum RoomPrivacy {
     /// Public room (anyone can join)
+    // Matching dailyco default
+    #[default]
     Public,
     /// Private room (need token to join, or owner approval)
     Private,
 }
 
-impl Default for RoomPrivacy {
-    fn default() -> Self {
-        // Matching dailyco default
-        Self::Public
-    }
+// An empty `properties` object is left out entirely, so that sending it can never be
+// taken as resetting the room configuration.
+fn no_properties_set(properties: &Option<RoomPropertiesBuilder>) -> bool {
+    properties.as_ref().map_or(true, |properties| {
+        *properties == RoomPropertiesBuilder::default()
+    })
 }
 
 /// A `CreateRoom` can be used to create a `Daily` room with custom configuration.
-#[derive(Debug, Copy, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct CreateRoom<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     privacy: Option<RoomPrivacy>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "no_properties_set")]
     properties: Option<RoomPropertiesBuilder<'a>>,
+    #[serde(skip)]
+    strict: bool,
+    #[serde(skip)]
+    idempotent: bool,
+    #[serde(skip)]
+    on_conflict: Conflict,
+    #[serde(skip)]
+    suffix_seed: Option<u64>,
+}
+
+/// What [CreateRoom::send] does when a room with the requested name already exists.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum Conflict {
+    /// Fail with the error returned by `Daily`.
+    #[default]
+    Error,
+    /// Return the existing room with the name instead.
+    ReturnExisting,
+    /// Create the room with a random suffix appended to the name instead, e.g.
+    /// `standup-k3x9q2`.
+    Suffix,
 }
 
+/// How many suffixed names are tried before giving up with [Conflict::Suffix].
+const MAX_SUFFIX_ATTEMPTS: u64 = 3;
+
 impl<'a> CreateRoom<'a> {
     /// Constructs a new `CreateRoom`.
     #[must_use]
@@ -59,6 +98,105 @@ impl<'a> CreateRoom<'a> {
         self
     }
 
+    /// Set the properties for this room from ones which are kept elsewhere, e.g. shared
+    /// by every room created. See [OwnedRoomProperties] for an example.
+    pub fn properties_owned(&mut self, properties: &'a OwnedRoomProperties) -> &mut Self {
+        self.properties(properties.as_builder())
+    }
+
+    /// Check the room `Daily` creates has the name, privacy and properties which were
+    /// requested, failing with [`Error::InvalidConfig`]
+    /// describing the first difference otherwise. Defaults to `false`.
+    ///
+    /// This catches settings silently overridden by `Daily`, e.g. because of the plan or
+    /// domain configuration. The room has still been created when this check fails.
+    /// Properties `Daily` doesn't return in the room's `config`, such as
+    /// [extra](RoomPropertiesBuilder::extra) ones it doesn't know, can't be checked.
+    ///
+    /// The properties are also checked with
+    /// [validate_strict](RoomPropertiesBuilder::validate_strict) before the room is
+    /// created.
+    pub fn strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Use the room's name as an idempotency key, so that `send` can be retried
+    /// without creating duplicate rooms or failing because an earlier attempt
+    /// succeeded. Defaults to `false`, and has no effect unless a [`name`](Self::name)
+    /// is set.
+    ///
+    /// `Daily` has no idempotency header, so when creating the room fails with a
+    /// transport or server error, or because a room with this name already exists, the
+    /// existing room with this name is returned instead. Note this is any room with the
+    /// name, including one created by something else, and it isn't checked by
+    /// [`strict`](Self::strict).
+    pub fn idempotent(&mut self, idempotent: bool) -> &mut Self {
+        self.idempotent = idempotent;
+        self
+    }
+
+    /// What to do when a room with the requested [`name`](Self::name) already exists.
+    /// Defaults to [Conflict::Error]. Ignored for [`idempotent`](Self::idempotent)
+    /// requests, which always return the existing room.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::room::{Conflict, CreateRoom, Room};
+    /// # async fn run() -> Result<Room> {
+    /// let client = Client::new("test-api-key")?;
+    /// // Creates e.g. `standup-k3x9q2` if `standup` is taken
+    /// let room = CreateRoom::new()
+    ///     .name("standup")
+    ///     .on_conflict(Conflict::Suffix)
+    ///     .send(&client)
+    ///     .await?;
+    /// # Ok(room)
+    /// # }
+    /// ```
+    pub fn on_conflict(&mut self, on_conflict: Conflict) -> &mut Self {
+        self.on_conflict = on_conflict;
+        self
+    }
+
+    /// Generate the suffixes of [Conflict::Suffix] from this seed, so that the same
+    /// names are tried every time, e.g. in tests. Defaults to a random seed.
+    pub fn suffix_seed(&mut self, seed: u64) -> &mut Self {
+        self.suffix_seed = Some(seed);
+        self
+    }
+
+    /// Make this an ephemeral room: it expires `duration` from now, at which point any
+    /// ongoing meeting is ended and the room is eventually deleted by `Daily`.
+    ///
+    /// This sets `exp` and `eject_at_room_exp` on the room properties, so it should be
+    /// called after [`properties`](Self::properties), which would replace them. Meeting
+    /// tokens setting `eject_at_token_exp` or `eject_after_elapsed` override when their
+    /// holder is ejected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::room::{Room, CreateRoom};
+    /// # async fn run() -> Result<Room> {
+    /// let client = Client::new("test-api-key")?;
+    /// let room = CreateRoom::new()
+    ///     .expire_after(Duration::from_secs(60 * 60))
+    ///     .send(&client)
+    ///     .await?;
+    /// # Ok(room)
+    /// # }
+    /// ```
+    pub fn expire_after(&mut self, duration: Duration) -> &mut Self {
+        let properties = self.properties.take().unwrap_or_default();
+        self.properties = Some(properties.expires_in(duration).eject_at_room_exp(true));
+        self
+    }
+
     /// Make the request to create the `Daily` room.
     ///
     /// # Examples
@@ -83,15 +221,144 @@ impl<'a> CreateRoom<'a> {
     /// # }
     /// ```
     pub async fn send(&self, client: &Client) -> crate::Result<Room> {
+        match &self.properties {
+            Some(properties) if self.strict => properties.validate_strict()?,
+            Some(properties) => properties.validate()?,
+            None => {}
+        }
+        match (self.create(client).await, self.name) {
+            (Err(err), Some(name)) if self.idempotent && may_have_been_created(&err) => {
+                client.try_get_room(name).await?.ok_or(err)
+            }
+            (Err(err), Some(name)) if is_name_taken(&err) => match self.on_conflict {
+                Conflict::Error => Err(err),
+                Conflict::ReturnExisting => client.get_room(name).await,
+                Conflict::Suffix => self.create_with_suffix(client, name, err).await,
+            },
+            (res, _) => res,
+        }
+    }
+
+    async fn create_with_suffix(
+        &self,
+        client: &Client,
+        name: &str,
+        mut err: Error,
+    ) -> crate::Result<Room> {
+        let seed = self.suffix_seed.unwrap_or_else(random_seed);
+        for attempt in 0..MAX_SUFFIX_ATTEMPTS {
+            let suffixed = format!("{name}-{}", name_suffix(seed.wrapping_add(attempt)));
+            let request = CreateRoom {
+                name: Some(&suffixed),
+                ..self.clone()
+            };
+            match request.create(client).await {
+                Err(taken) if is_name_taken(&taken) => err = taken,
+                res => return res,
+            }
+        }
+        Err(err)
+    }
+
+    async fn create(&self, client: &Client) -> crate::Result<Room> {
         // This should not be able to fail
         let room_url = client.base_url.join("rooms/").unwrap();
-        let resp = client.client.post(room_url).json(self).send().await?;
-        parse_dailyco_response(resp).await
+        let resp = client.execute(client.post_json(room_url, self)).await?;
+        if !self.strict {
+            return parse_dailyco_response(resp).await;
+        }
+
+        let created: Value = parse_dailyco_response(resp).await?;
+        let room: Room =
+            serde_json::from_value(created.clone()).map_err(|source| Error::Deserialize {
+                body: created.to_string(),
+                request_id: None,
+                source,
+            })?;
+        // This should not be able to fail, the builder always serializes to an object
+        let Value::Object(requested) = serde_json::to_value(self).unwrap() else {
+            unreachable!("CreateRoom serializes to an object");
+        };
+        // Anything `Daily` doesn't echo back can't be checked, so it is skipped
+        for (key, value) in &requested {
+            if key == "properties" {
+                continue;
+            }
+            if let Some(created) = created.get(key) {
+                check_created_as_requested(&room.name, key, value, created)?;
+            }
+        }
+        if let Some(Value::Object(properties)) = requested.get("properties") {
+            for (key, value) in properties {
+                if let Some(created) = created["config"].get(key) {
+                    check_created_as_requested(&room.name, key, value, created)?;
+                }
+            }
+        }
+        Ok(room)
+    }
+}
+
+/// Whether a room may exist despite creating it failing with `err`, because the
+/// request could have been handled or the room already existed.
+fn may_have_been_created(err: &Error) -> bool {
+    match err {
+        Error::Request(_) => true,
+        Error::APIError(info) => {
+            info.error == Some(DailyCoErrorKind::ServerError) || is_name_taken(err)
+        }
+        _ => false,
+    }
+}
+
+fn is_name_taken(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::APIError(info) if info.invalid_request_detail() == Some(InvalidRequestDetail::NameTaken)
+    )
+}
+
+fn random_seed() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    // Randomly keyed for each `RandomState`, which avoids a dependency for randomness
+    std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish()
+}
+
+/// Six lowercase letters and digits derived from `seed`, which are valid in a room name.
+fn name_suffix(seed: u64) -> String {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+    // splitmix64, so that consecutive seeds give unrelated suffixes
+    let mut x = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^= x >> 31;
+    (0..6)
+        .map(|i| char::from(ALPHABET[(x >> (i * 8)) as usize % ALPHABET.len()]))
+        .collect()
+}
+
+fn check_created_as_requested(
+    room_name: &str,
+    key: &str,
+    requested: &Value,
+    created: &Value,
+) -> crate::Result<()> {
+    if matches_requested(requested, created) {
+        Ok(())
+    } else {
+        Err(Error::invalid_config(format!(
+            "room `{room_name}` was created with `{key}` set to {created} instead of the requested {requested}"
+        )))
     }
 }
 
 /// Room object metadata as reported by `Daily`.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-deserialize", serde(deny_unknown_fields))]
 pub struct Room {
     /// The id for this room.
     pub id: String,
@@ -110,12 +377,249 @@ pub struct Room {
     pub config: RoomProperties,
 }
 
+impl Room {
+    /// The URL joining this room with a meeting token, i.e. [`url`](Self::url) with
+    /// `t=<token>` added to its query string.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::meeting_token::CreateMeetingToken;
+    /// # async fn run() -> Result<String> {
+    /// let client = Client::new("test-api-key")?;
+    /// let room = client.get_room("a-room").await?;
+    /// let token = CreateMeetingToken::new()
+    ///     .room_name(&room.name)
+    ///     .send(&client)
+    ///     .await?;
+    /// # Ok(room.join_url_with_token(token.as_str()))
+    /// # }
+    /// ```
+    pub fn join_url_with_token(&self, token: &str) -> String {
+        match Url::parse(&self.url) {
+            Ok(mut url) => {
+                url.query_pairs_mut().append_pair("t", token);
+                url.into()
+            }
+            // `Daily` always returns a valid URL, but don't lose the token if it didn't
+            Err(_) => format!("{}?t={token}", self.url),
+        }
+    }
+}
+
+/// A room together with a meeting token for it, as made by
+/// [Client::create_room_with_token](crate::Client::create_room_with_token).
+#[derive(Debug, Clone)]
+pub struct JoinLink {
+    /// The room which was created.
+    pub room: Room,
+    /// The meeting token created for the room.
+    pub token: MeetingTokenString,
+    /// The room's URL with the token appended, so that following it joins the room
+    /// with the token's properties.
+    pub url: String,
+}
+
+/// A builder for the `/rooms` request, listing the rooms of the domain one page at a time.
+///
+/// This endpoint is detailed in <https://docs.daily.co/reference/rest-api/rooms/list-rooms>
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct GetRooms {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ending_before: Option<Cursor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    starting_after: Option<Cursor>,
+    #[serde(skip)]
+    name_prefix: Option<String>,
+}
+
+impl GetRooms {
+    /// Constructs a new `GetRooms`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The size of the page (how many rooms it contains), defaults to 100.
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Fetch the page of rooms before this cursor.
+    pub fn ending_before(&mut self, ending_before: impl Into<Cursor>) -> &mut Self {
+        self.ending_before = Some(ending_before.into());
+        self
+    }
+
+    /// Fetch the page of rooms after this cursor.
+    pub fn starting_after(&mut self, starting_after: impl Into<Cursor>) -> &mut Self {
+        self.starting_after = Some(starting_after.into());
+        self
+    }
+
+    /// Only stream the rooms whose name starts with `prefix`, e.g. the rooms of one
+    /// tenant named `tenant-123-*`.
+    ///
+    /// `Daily` can't filter rooms by name, so every room is still fetched and the
+    /// filter is applied by [into_stream](Self::into_stream). Pages returned by
+    /// [send](Self::send) are never filtered, as that would break their cursors.
+    pub fn name_prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.name_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Return a single [Page] of rooms, with the cursors to request the pages around it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::room::{GetRooms, Room};
+    /// # async fn run() -> Result<Vec<Room>> {
+    /// let client = Client::new("test-api-key")?;
+    /// let mut rooms = Vec::new();
+    /// let mut request = GetRooms::new();
+    /// loop {
+    ///     let page = request.send(&client).await?;
+    ///     let next_cursor = page.next_cursor().cloned();
+    ///     rooms.extend(page);
+    ///     match next_cursor {
+    ///         Some(cursor) => request.starting_after(cursor),
+    ///         None => break,
+    ///     };
+    /// }
+    /// # Ok(rooms)
+    /// # }
+    /// ```
+    pub async fn send(&self, client: &Client) -> crate::Result<Page<Room>> {
+        #[derive(Deserialize)]
+        struct GetRoomsResponse {
+            total_count: usize,
+            data: Vec<Room>,
+        }
+
+        // This should not be able to fail
+        let url = client.base_url.join("rooms/").unwrap();
+        let resp = client.execute(client.get(url).query(self)).await?;
+        let rooms: GetRoomsResponse = parse_dailyco_response(resp).await?;
+        let limit = self.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+        let from_cursor = self.starting_after.is_some() || self.ending_before.is_some();
+        Ok(Page::new(rooms.data, rooms.total_count, limit, from_cursor))
+    }
+
+    /// Return a stream of every room matching this request, fetching further pages
+    /// as needed until a page shorter than `limit` is returned.
+    ///
+    /// Pages after the first are requested with `starting_after` set to the last room
+    /// received, keeping the `limit` of this request. Rooms not matching the
+    /// [name_prefix](Self::name_prefix) are skipped.
+    ///
+    /// Each room is returned at most once: a room which shows up on two pages, e.g.
+    /// because the cursor shifted as rooms were created concurrently, is only
+    /// returned the first time. Rooms which exist for the whole traversal are all
+    /// returned, but ones created or deleted while it is under way may be missed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result};
+    /// # use dailyco::room::{GetRooms, Room};
+    /// use futures_util::TryStreamExt;
+    /// # async fn run() -> Result<Vec<Room>> {
+    /// let client = Client::new("test-api-key")?;
+    /// let rooms = GetRooms::new()
+    ///     .name_prefix("tenant-123-")
+    ///     .into_stream(&client)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(rooms)
+    /// # }
+    /// ```
+    pub fn into_stream<'c>(
+        &self,
+        client: &'c Client,
+    ) -> impl Stream<Item = crate::Result<Room>> + 'c {
+        let first_page = self.clone();
+        let name_prefix = self.name_prefix.clone().unwrap_or_default();
+        let mut seen = HashSet::new();
+        stream::try_unfold(Some(first_page), move |request| async move {
+            let Some(request) = request else {
+                return crate::Result::Ok(None);
+            };
+            let page = request.send(client).await?;
+            let next_page = page.next_cursor().map(|cursor| GetRooms {
+                ending_before: None,
+                starting_after: Some(cursor.clone()),
+                ..request
+            });
+            let rooms = page.into_iter().map(Ok);
+            Ok(Some((stream::iter(rooms), next_page)))
+        })
+        .try_flatten()
+        .try_filter(move |room| {
+            future::ready(seen.insert(room.id.clone()) && room.name.starts_with(&name_prefix))
+        })
+    }
+}
+
+/// The body of the request `Daily` sends to a room's
+/// [meeting_join_hook](crate::RoomPropertiesBuilder::meeting_join_hook) when a user joins it.
+///
+/// `Daily` doesn't sign these requests, so the hook URL itself should be hard to guess,
+/// e.g. by including a secret path segment, if the handler must only trust `Daily`.
+///
+/// # Examples
+///
+/// ```
+/// # use dailyco::room::JoinHookEvent;
+/// # fn run() -> dailyco::Result<()> {
+/// let body = br#"{"room":"a-room","user_id":"user-1","user_name":"Ada","timestamp":1700000000}"#;
+/// let event = JoinHookEvent::parse(body)?;
+/// assert_eq!(event.room, "a-room");
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct JoinHookEvent {
+    /// The name of the room which was joined.
+    pub room: String,
+    /// The id of the user who joined, if set by their meeting token.
+    pub user_id: Option<String>,
+    /// The name of the user who joined.
+    pub user_name: Option<String>,
+    /// Unix timestamp of when the user joined.
+    pub timestamp: Option<i64>,
+    /// Fields sent by `Daily` which aren't modelled by this struct.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl JoinHookEvent {
+    /// Parse the raw body of a join hook request.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Deserialize`] if the body isn't a join hook event.
+    pub fn parse(body: &[u8]) -> crate::Result<Self> {
+        serde_json::from_slice(body).map_err(|source| crate::Error::Deserialize {
+            body: String::from_utf8_lossy(body).into_owned(),
+            request_id: None,
+            source,
+        })
+    }
+}
+
 /// An `UpdateRoom` can be used to update an existing `Daily` room.
-#[derive(Debug, Copy, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct UpdateRoom<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     privacy: Option<RoomPrivacy>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "no_properties_set")]
     properties: Option<RoomPropertiesBuilder<'a>>,
 }
 
@@ -163,9 +667,12 @@ impl<'a> UpdateRoom<'a> {
     /// # }
     /// ```
     pub async fn send(&self, room_name: &str, client: &Client) -> crate::Result<Room> {
+        if let Some(properties) = &self.properties {
+            properties.validate()?;
+        }
         // This should not be able to fail
         let room_url = client.base_url.join(&format!("rooms/{room_name}")).unwrap();
-        let resp = client.client.post(room_url).json(self).send().await?;
+        let resp = client.execute(client.post_json(room_url, self)).await?;
         parse_dailyco_response(resp).await
     }
 }
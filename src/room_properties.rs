@@ -1,110 +1,257 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use crate::configuration::{
-    DailyLang, RecordingType, RecordingsBucket, Region, RtmpGeoRegion, SignalingImp,
+    AutoTranscriptionSettings, DailyLang, RecordingType, RecordingsBucket, Region, RtmpGeoRegion,
+    SfuSwitchover, SignalingImp, SipSettings,
 };
-use crate::utils::default_as_true;
+use crate::meeting_token::TokenPermissions;
+use crate::utils::{matches_requested, timestamp_in, validate_timing, whole_secs};
 
 /// Properties for a `Daily` room, defined [here](https://docs.daily.co/reference/rest-api/rooms/config).
-/// Following the API docs, fields not found in a request are assumed to have their
-/// default values.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// `Daily` only returns the properties set on a room, and a room created with some
+/// properties only gets those echoed back, so every setting is an `Option`: `None`
+/// means `Daily` didn't return it, and the room uses its domain's configuration or
+/// `Daily`'s documented default. This tells "set to false" apart from "not set" for
+/// toggles like `enable_chat` or `enable_network_ui`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RoomProperties {
     /// UTC timestamp before which the room cannot be joined
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nbf: Option<i64>,
     /// UTC timestamp for expiration of the room, after which
     /// time it will be automatically deleted at some point.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub exp: Option<i64>,
     /// Maximum number of participants who can enter the room.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_participants: Option<usize>,
     /// Determines if Daily Prebuilt displays the People UI
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enable_people_ui: Option<bool>,
     /// Sets whether the room can use Daily Prebuilt's Picture in Picture controls.
-    #[serde(default)]
-    pub enable_pip_ui: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_pip_ui: Option<bool>,
     /// Determines whether participants enter a waiting room with a camera, mic, and
     /// browser check before joining a call.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enable_prejoin_ui: Option<bool>,
     /// Determines whether the network button, and the network panel it reveals on click, appears in this room.
-    #[serde(default)]
-    pub enable_network_ui: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_network_ui: Option<bool>,
     /// Turns on a lobby experience for private rooms. A participant without a corresponding
     /// meeting token can request to be admitted to the meeting with a "knock", and wait
     /// for the meeting owner to admit them.
-    #[serde(default)]
-    pub enable_knocking: bool,
-    /// Whether or not screen-sharing is enabled
-    #[serde(default = "default_as_true")]
-    pub enable_screenshare: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_knocking: Option<bool>,
+    /// Whether or not screen-sharing is enabled. `Daily` enables it unless set to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_screenshare: Option<bool>,
     /// Determines whether Daily Prebuilt displays background blur controls.
-    #[serde(default = "default_as_true")]
-    pub enable_video_processing_ui: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_video_processing_ui: Option<bool>,
     /// Allow adding chat to the call
-    #[serde(default)]
-    pub enable_chat: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_chat: Option<bool>,
+    /// Use the advanced chat in Daily Prebuilt, which supports emoji reactions
+    /// and sharing files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_advanced_chat: Option<bool>,
     /// Keep video off when room is joined
-    #[serde(default)]
-    pub start_video_off: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_video_off: Option<bool>,
     /// Keep audio off when room is joined
-    #[serde(default)]
-    pub start_audio_off: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_audio_off: Option<bool>,
     /// In Daily Prebuilt, only the meeting owners will be able to turn on camera,
     /// unmute mic, and share screen
-    #[serde(default)]
-    pub owner_only_broadcast: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_only_broadcast: Option<bool>,
     /// Allowed recording type for the room
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enable_recording: Option<RecordingType>,
     /// If there's a meeting going on at room exp time, end the meeting by kicking
     /// everyone out. This behavior can be overridden by setting eject properties of
     /// a meeting token.
-    #[serde(default)]
-    pub eject_at_room_exp: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eject_at_room_exp: Option<bool>,
     /// Eject a meeting participant this many seconds after the participant joins the
     /// meeting. You can use this is a default length limit to prevent long meetings.
     /// This can be overridden by setting eject properties of a meeting token.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub eject_after_elapsed: Option<i64>,
     /// When enabled, non-owner users join a meeting with a hidden presence, meaning
     /// they won't appear as a named participant in the meeting and have no participant
     /// events associated to them.
-    #[serde(default)]
-    pub enable_hidden_participants: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_hidden_participants: Option<bool>,
     /// Configures a room to use multiple SFUs for a call's media. This feature enables
     /// calls to scale to large sizes and to reduce latency between participants.
     /// It is recommended specifically for interactive live streaming.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enable_mesh_sfu: Option<bool>,
     /// Enables Daily Prebuilt to support group calls of up to 300 participants and
     /// owner only broadcast calls of up to 15K participants.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental_optimize_large_calls: Option<bool>,
     /// The default language of the Daily prebuilt video call UI, for this room.
-    #[serde(default)]
-    pub lang: DailyLang,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<DailyLang>,
     /// Sets a URL that will receive a webhook when a user joins a room.
     /// Default is NULL. Character limit for webhook URL is 255.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meeting_join_hook: Option<String>,
     /// Sets the signaling type.
-    #[serde(default)]
-    pub signaling_imp: SignalingImp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signaling_imp: Option<SignalingImp>,
     /// Enforce a signaling server region
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub geo: Option<Region>,
     /// Used to select the region where an RTMP stream should originate.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rtmp_geo: Option<RtmpGeoRegion>,
     /// Reduces the volume of log messages. This feature should be enabled when there
     /// are more than 200 participants in a meeting to help improve performance.
-    #[serde(default)]
-    pub enable_terse_logging: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_terse_logging: Option<bool>,
     /// See details in the [docs](https://docs.daily.co/reference/rest-api/rooms/config#recordings_template).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recordings_template: Option<String>,
     /// Configures an S3 bucket in which to store recordings.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recordings_bucket: Option<RecordingsBucket>,
+    /// Start transcription as soon as a participant joins the room.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_start_transcription: Option<bool>,
+    /// Settings for transcription started automatically, `None` uses `Daily`'s defaults.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_transcription_settings: Option<AutoTranscriptionSettings>,
     /// Dictates the participant count after which room topology automatically
     /// switches from Peer-to-Peer (P2P) to Selective Forwarding Unit (SFU) mode, or vice versa.
     /// Default is 0.5
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sfu_switchover: Option<f64>,
+    /// Whether participants can dial in to the room by phone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_dialin: Option<bool>,
+    /// SIP interconnect settings, for SIP endpoints to join the room.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sip: Option<SipSettings>,
+    /// The permissions participants have by default, unless their meeting token grants
+    /// others.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<TokenPermissions>,
+    /// Properties returned by `Daily` which aren't modelled by this struct, such as
+    /// features newer than this crate.
+    ///
+    /// Always empty with the `strict-deserialize` feature, which rejects these instead.
+    #[serde(flatten)]
+    #[cfg_attr(
+        feature = "strict-deserialize",
+        serde(deserialize_with = "crate::utils::deny_unmodelled")
+    )]
+    pub extra: Map<String, Value>,
+}
+
+impl RoomProperties {
+    /// The properties set on `builder` which differ from these, e.g. to tell a room's
+    /// `config` has drifted from the template it was created with.
+    ///
+    /// Properties the builder leaves unset are never reported. Properties set on the
+    /// builder which `Daily` didn't return, like domain settings the room inherits, are
+    /// reported with a `current` value of `null`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dailyco::{Client, Result, RoomPropertiesBuilder};
+    /// # async fn run() -> Result<()> {
+    /// let client = Client::new("test-api-key")?;
+    /// let template = RoomPropertiesBuilder::new().max_participants(8);
+    /// let room = client.get_room("a-room").await?;
+    /// for diff in room.config.diff(&template) {
+    ///     println!("{} is {} instead of {}", diff.field, diff.current, diff.desired);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, builder: &RoomPropertiesBuilder) -> Vec<PropertyDiff> {
+        // Neither of these should be able to fail, both serialize to objects
+        let current = serde_json::to_value(self).unwrap();
+        let Value::Object(desired) = serde_json::to_value(builder).unwrap() else {
+            unreachable!("RoomPropertiesBuilder serializes to an object");
+        };
+        desired
+            .into_iter()
+            .filter_map(|(field, desired)| {
+                let current = current.get(&field).cloned().unwrap_or(Value::Null);
+                (!matches_requested(&desired, &current)).then_some(PropertyDiff {
+                    field,
+                    current,
+                    desired,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A room property which differs from the value wanted for it, as found by
+/// [`RoomProperties::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyDiff {
+    /// The name of the property, as used by `Daily`.
+    pub field: String,
+    /// The property's value on the room.
+    pub current: Value,
+    /// The value wanted for the property.
+    pub desired: Value,
 }
 
+/// Names of the properties [`RoomPropertiesBuilder`] has a typed setter for.
+const TYPED_PROPERTIES: &[&str] = &[
+    "nbf",
+    "exp",
+    "max_participants",
+    "enable_people_ui",
+    "enable_pip_ui",
+    "enable_prejoin_ui",
+    "enable_network_ui",
+    "enable_knocking",
+    "enable_screenshare",
+    "enable_video_processing_ui",
+    "enable_chat",
+    "enable_advanced_chat",
+    "start_video_off",
+    "start_audio_off",
+    "owner_only_broadcast",
+    "enable_recording",
+    "eject_at_room_exp",
+    "eject_after_elapsed",
+    "enable_hidden_participants",
+    "enable_mesh_sfu",
+    "experimental_optimize_large_calls",
+    "lang",
+    "meeting_join_hook",
+    "signaling_imp",
+    "geo",
+    "rtmp_geo",
+    "enable_terse_logging",
+    "recordings_template",
+    "recordings_bucket",
+    "auto_start_transcription",
+    "auto_transcription_settings",
+    "sfu_switchover",
+    "enable_dialin",
+    "sip",
+    "permissions",
+];
+
 /// A builder to specify properties for a `Daily` room,
 /// defined [here](https://docs.daily.co/reference/rest-api/rooms/config).
-#[derive(Debug, Copy, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
 pub struct RoomPropertiesBuilder<'a> {
     /// UTC timestamp before which the room cannot be joined
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -143,6 +290,10 @@ pub struct RoomPropertiesBuilder<'a> {
     /// Allow adding chat to the call
     #[serde(skip_serializing_if = "Option::is_none")]
     enable_chat: Option<bool>,
+    /// Use the advanced chat in Daily Prebuilt, which supports emoji reactions
+    /// and sharing files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_advanced_chat: Option<bool>,
     /// Keep video off when room is joined
     #[serde(skip_serializing_if = "Option::is_none")]
     start_video_off: Option<bool>,
@@ -206,13 +357,35 @@ pub struct RoomPropertiesBuilder<'a> {
     /// Configures an S3 bucket in which to store recordings.
     #[serde(skip_serializing_if = "Option::is_none")]
     recordings_bucket: Option<&'a RecordingsBucket>,
+    /// Start transcription as soon as a participant joins the room.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_start_transcription: Option<bool>,
+    /// Settings for transcription started automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_transcription_settings: Option<&'a AutoTranscriptionSettings>,
     /// Dictates the participant count after which room topology automatically
     /// switches from Peer-to-Peer (P2P) to Selective Forwarding Unit (SFU) mode, or vice versa.
     #[serde(skip_serializing_if = "Option::is_none")]
     sfu_switchover: Option<f64>,
+    /// Whether participants can dial in to the room by phone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_dialin: Option<bool>,
+    /// SIP interconnect settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sip: Option<&'a SipSettings>,
+    /// The permissions participants have by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<&'a TokenPermissions>,
+    /// Properties without a typed setter, sent alongside the typed ones.
+    #[serde(flatten)]
+    extra: Map<String, Value>,
 }
 
 impl<'a> RoomPropertiesBuilder<'a> {
+    /// The number of participants above which `Daily` recommends
+    /// [terse logging](Self::enable_terse_logging).
+    pub const TERSE_LOGGING_PARTICIPANTS: usize = 200;
+
     /// Start a new `RoomPropertiesBuilder`.
     pub fn new() -> Self {
         Self::default()
@@ -231,6 +404,46 @@ impl<'a> RoomPropertiesBuilder<'a> {
         self
     }
 
+    /// The room cannot be joined until `duration` from now. Sets `nbf` relative to
+    /// the current time.
+    pub fn not_before_in(self, duration: Duration) -> Self {
+        self.nbf(timestamp_in(duration))
+    }
+
+    /// The room expires `duration` from now. Sets `exp` relative to the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// let properties = RoomPropertiesBuilder::new().expires_in(Duration::from_secs(60 * 60));
+    /// ```
+    pub fn expires_in(self, duration: Duration) -> Self {
+        self.exp(timestamp_in(duration))
+    }
+
+    /// The room can be joined from `start_in` from now, and expires `lasting` after
+    /// that. Sets both `nbf` and `exp` from a single reading of the current time.
+    ///
+    /// A `lasting` of less than a second leaves `nbf` not before `exp`, which
+    /// [validate](Self::validate) rejects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// // Open in 15 minutes, for an hour
+    /// let properties = RoomPropertiesBuilder::new()
+    ///     .valid_window(Duration::from_secs(15 * 60), Duration::from_secs(60 * 60));
+    /// ```
+    pub fn valid_window(self, start_in: Duration, lasting: Duration) -> Self {
+        let nbf = timestamp_in(start_in);
+        let lasting = i64::try_from(lasting.as_secs()).unwrap_or(i64::MAX);
+        self.nbf(nbf).exp(nbf.saturating_add(lasting))
+    }
+
     /// Maximum number of participants who can enter the room.
     pub fn max_participants(mut self, max_participants: usize) -> Self {
         self.max_participants = Some(max_participants);
@@ -288,6 +501,13 @@ impl<'a> RoomPropertiesBuilder<'a> {
         self
     }
 
+    /// Use the advanced chat in `Daily Prebuilt`, which supports emoji reactions
+    /// and sharing files.
+    pub fn enable_advanced_chat(mut self, enable_advanced_chat: bool) -> Self {
+        self.enable_advanced_chat = Some(enable_advanced_chat);
+        self
+    }
+
     /// Keep video off when room is joined
     pub fn start_video_off(mut self, start_video_off: bool) -> Self {
         self.start_video_off = Some(start_video_off);
@@ -329,6 +549,21 @@ impl<'a> RoomPropertiesBuilder<'a> {
         self
     }
 
+    /// Eject a meeting participant `duration` after they join the meeting, see
+    /// [`eject_after_elapsed`](Self::eject_after_elapsed). A partial second is rounded
+    /// up to a whole one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// let properties = RoomPropertiesBuilder::new().eject_after(Duration::from_secs(45 * 60));
+    /// ```
+    pub fn eject_after(self, duration: Duration) -> Self {
+        self.eject_after_elapsed(whole_secs(duration))
+    }
+
     /// When enabled, non-owner users join a meeting with a hidden presence, meaning
     /// they won't appear as a named participant in the meeting and have no participant
     /// events associated to them.
@@ -340,6 +575,11 @@ impl<'a> RoomPropertiesBuilder<'a> {
     /// Configures a room to use multiple SFUs for a call's media. This feature enables
     /// calls to scale to large sizes and to reduce latency between participants.
     /// It is recommended specifically for interactive live streaming.
+    ///
+    /// Mesh SFU rooms always use SFU, so this can't be combined with
+    /// [`sfu_switchover`](Self::sfu_switchover) or [`sfu_always`](Self::sfu_always).
+    /// For large interactive streams, enable this alone, optionally together with
+    /// [`experimental_optimize_large_calls`](Self::experimental_optimize_large_calls).
     pub fn enable_mesh_sfu(mut self, enable_mesh_sfu: bool) -> Self {
         self.enable_mesh_sfu = Some(enable_mesh_sfu);
         self
@@ -387,12 +627,36 @@ impl<'a> RoomPropertiesBuilder<'a> {
     }
 
     /// Reduces the volume of log messages. This feature should be enabled when there
-    /// are more than 300 participants in a meeting to help improve performance.
+    /// are more than 200 participants in a meeting to help improve performance.
     pub fn enable_terse_logging(mut self, enable_terse_logging: bool) -> Self {
         self.enable_terse_logging = Some(enable_terse_logging);
         self
     }
 
+    /// Enable terse logging if `max_participants` is set above
+    /// [TERSE_LOGGING_PARTICIPANTS](Self::TERSE_LOGGING_PARTICIPANTS), as `Daily`
+    /// recommends for large meetings.
+    ///
+    /// Otherwise, or if terse logging was already set either way, this does nothing.
+    /// Since it looks at `max_participants`, call it after setting that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// let properties = RoomPropertiesBuilder::new()
+    ///     .max_participants(1000)
+    ///     .auto_terse_logging();
+    /// ```
+    pub fn auto_terse_logging(self) -> Self {
+        match (self.max_participants, self.enable_terse_logging) {
+            (Some(max), None) if max > Self::TERSE_LOGGING_PARTICIPANTS => {
+                self.enable_terse_logging(true)
+            }
+            _ => self,
+        }
+    }
+
     /// See details in the [docs](https://docs.daily.co/reference/rest-api/rooms/config#recordings_template).
     pub fn recordings_template(mut self, recordings_template: &'a str) -> Self {
         self.recordings_template = Some(recordings_template);
@@ -401,7 +665,25 @@ impl<'a> RoomPropertiesBuilder<'a> {
 
     /// Dictates the participant count after which room topology automatically
     /// switches from Peer-to-Peer (P2P) to Selective Forwarding Unit (SFU) mode, or vice versa.
-    pub fn sfu_switchover(mut self, sfu_switchover: f64) -> Self {
+    ///
+    /// Has no meaning for a room with [`enable_mesh_sfu`](Self::enable_mesh_sfu) set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// # use dailyco::configuration::SfuSwitchover;
+    /// let properties = RoomPropertiesBuilder::new().sfu_switchover(SfuSwitchover::AtCount(4));
+    /// ```
+    pub fn sfu_switchover(mut self, sfu_switchover: SfuSwitchover) -> Self {
+        self.sfu_switchover = Some(sfu_switchover.as_f64());
+        self
+    }
+
+    /// Set `sfu_switchover` to a raw value, see [sfu_switchover](Self::sfu_switchover).
+    /// A negative or NaN value makes [validate](Self::validate) fail.
+    #[deprecated(since = "0.8.0", note = "use `sfu_switchover` with an `SfuSwitchover`")]
+    pub fn sfu_switchover_f64(mut self, sfu_switchover: f64) -> Self {
         self.sfu_switchover = Some(sfu_switchover);
         self
     }
@@ -412,10 +694,498 @@ impl<'a> RoomPropertiesBuilder<'a> {
         self
     }
 
+    /// Start transcription as soon as a participant joins the room, using the
+    /// [`auto_transcription_settings`](Self::auto_transcription_settings) if set.
+    pub fn auto_start_transcription(mut self, auto_start_transcription: bool) -> Self {
+        self.auto_start_transcription = Some(auto_start_transcription);
+        self
+    }
+
+    /// Settings for transcription started automatically in this room.
+    pub fn auto_transcription_settings(
+        mut self,
+        auto_transcription_settings: &'a AutoTranscriptionSettings,
+    ) -> Self {
+        self.auto_transcription_settings = Some(auto_transcription_settings);
+        self
+    }
+
+    /// Whether participants can dial in to the room by phone.
+    pub fn enable_dialin(mut self, enable_dialin: bool) -> Self {
+        self.enable_dialin = Some(enable_dialin);
+        self
+    }
+
+    /// SIP interconnect settings, so that SIP endpoints can join the room.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// # use dailyco::configuration::{SipMode, SipSettings};
+    /// let sip = SipSettings {
+    ///     display_name: Some("Front desk".to_string()),
+    ///     sip_mode: Some(SipMode::DialIn),
+    ///     ..SipSettings::default()
+    /// };
+    /// let properties = RoomPropertiesBuilder::new().enable_dialin(true).sip(&sip);
+    /// ```
+    pub fn sip(mut self, sip: &'a SipSettings) -> Self {
+        self.sip = Some(sip);
+        self
+    }
+
+    /// The permissions participants have by default, e.g. to make everyone listen-only
+    /// in a webinar with `can_send` set to `Allowed::All(false)`.
+    ///
+    /// A meeting token's `permissions` take precedence over these for its participant,
+    /// so hosts can be given more with their tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// # use dailyco::meeting_token::{Allowed, TokenPermissions};
+    /// let listen_only = TokenPermissions {
+    ///     can_send: Some(Allowed::All(false)),
+    ///     ..TokenPermissions::default()
+    /// };
+    /// let properties = RoomPropertiesBuilder::new().permissions(&listen_only);
+    /// ```
+    pub fn permissions(mut self, permissions: &'a TokenPermissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
     /// Ensure the room always immediately switches to SFU. Equivalent to setting
-    /// `sfu_switchover` to the default 0.5
-    pub fn sfu_always(mut self) -> Self {
-        self.sfu_switchover = Some(0.5);
+    /// `sfu_switchover` to [SfuSwitchover::Always].
+    pub fn sfu_always(self) -> Self {
+        self.sfu_switchover(SfuSwitchover::Always)
+    }
+
+    /// Set a room property this builder has no typed setter for, e.g. one recently added
+    /// by `Daily`. The value is sent as-is alongside the typed properties.
+    ///
+    /// Typed properties always take precedence: if `key` names a property which has a
+    /// dedicated setter, it is ignored and the dedicated setter should be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// let properties = RoomPropertiesBuilder::new()
+    ///     .start_audio_off(true)
+    ///     .extra("enable_emoji_reactions", serde_json::Value::Bool(true));
+    /// ```
+    pub fn extra(mut self, key: &str, value: Value) -> Self {
+        if !TYPED_PROPERTIES.contains(&key) {
+            self.extra.insert(key.to_string(), value);
+        }
         self
     }
+
+    /// Combine these properties, e.g. a base template, with `other` overriding them.
+    ///
+    /// Each property set on `other` replaces the one on `self`, while properties `other`
+    /// leaves unset keep their value from `self`. A property can't be unset by merging.
+    /// [Extra](Self::extra) properties are merged by key in the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// let base = RoomPropertiesBuilder::new()
+    ///     .max_participants(10)
+    ///     .start_video_off(true);
+    /// let properties = base.clone().merge(RoomPropertiesBuilder::new().max_participants(4));
+    /// assert_eq!(
+    ///     properties,
+    ///     RoomPropertiesBuilder::new()
+    ///         .max_participants(4)
+    ///         .start_video_off(true)
+    /// );
+    /// ```
+    pub fn merge(mut self, other: RoomPropertiesBuilder<'a>) -> Self {
+        let RoomPropertiesBuilder {
+            nbf,
+            exp,
+            max_participants,
+            enable_people_ui,
+            enable_pip_ui,
+            enable_prejoin_ui,
+            enable_network_ui,
+            enable_knocking,
+            enable_screenshare,
+            enable_video_processing_ui,
+            enable_chat,
+            enable_advanced_chat,
+            start_video_off,
+            start_audio_off,
+            owner_only_broadcast,
+            enable_recording,
+            eject_at_room_exp,
+            eject_after_elapsed,
+            enable_hidden_participants,
+            enable_mesh_sfu,
+            experimental_optimize_large_calls,
+            lang,
+            meeting_join_hook,
+            signaling_imp,
+            geo,
+            rtmp_geo,
+            enable_terse_logging,
+            recordings_template,
+            recordings_bucket,
+            auto_start_transcription,
+            auto_transcription_settings,
+            sfu_switchover,
+            enable_dialin,
+            sip,
+            permissions,
+            extra,
+        } = other;
+        self.extra.extend(extra);
+        Self {
+            nbf: nbf.or(self.nbf),
+            exp: exp.or(self.exp),
+            max_participants: max_participants.or(self.max_participants),
+            enable_people_ui: enable_people_ui.or(self.enable_people_ui),
+            enable_pip_ui: enable_pip_ui.or(self.enable_pip_ui),
+            enable_prejoin_ui: enable_prejoin_ui.or(self.enable_prejoin_ui),
+            enable_network_ui: enable_network_ui.or(self.enable_network_ui),
+            enable_knocking: enable_knocking.or(self.enable_knocking),
+            enable_screenshare: enable_screenshare.or(self.enable_screenshare),
+            enable_video_processing_ui: enable_video_processing_ui
+                .or(self.enable_video_processing_ui),
+            enable_chat: enable_chat.or(self.enable_chat),
+            enable_advanced_chat: enable_advanced_chat.or(self.enable_advanced_chat),
+            start_video_off: start_video_off.or(self.start_video_off),
+            start_audio_off: start_audio_off.or(self.start_audio_off),
+            owner_only_broadcast: owner_only_broadcast.or(self.owner_only_broadcast),
+            enable_recording: enable_recording.or(self.enable_recording),
+            eject_at_room_exp: eject_at_room_exp.or(self.eject_at_room_exp),
+            eject_after_elapsed: eject_after_elapsed.or(self.eject_after_elapsed),
+            enable_hidden_participants: enable_hidden_participants
+                .or(self.enable_hidden_participants),
+            enable_mesh_sfu: enable_mesh_sfu.or(self.enable_mesh_sfu),
+            experimental_optimize_large_calls: experimental_optimize_large_calls
+                .or(self.experimental_optimize_large_calls),
+            lang: lang.or(self.lang),
+            meeting_join_hook: meeting_join_hook.or(self.meeting_join_hook),
+            signaling_imp: signaling_imp.or(self.signaling_imp),
+            geo: geo.or(self.geo),
+            rtmp_geo: rtmp_geo.or(self.rtmp_geo),
+            enable_terse_logging: enable_terse_logging.or(self.enable_terse_logging),
+            recordings_template: recordings_template.or(self.recordings_template),
+            recordings_bucket: recordings_bucket.or(self.recordings_bucket),
+            auto_start_transcription: auto_start_transcription.or(self.auto_start_transcription),
+            auto_transcription_settings: auto_transcription_settings
+                .or(self.auto_transcription_settings),
+            sfu_switchover: sfu_switchover.or(self.sfu_switchover),
+            enable_dialin: enable_dialin.or(self.enable_dialin),
+            sip: sip.or(self.sip),
+            permissions: permissions.or(self.permissions),
+            extra: self.extra,
+        }
+    }
+
+    /// Check for settings which contradict each other or can never be satisfied:
+    /// `nbf` not before `exp`, a `max_participants` of 0, a negative
+    /// `eject_after_elapsed`, a negative or NaN `sfu_switchover`, or an `sfu_switchover`
+    /// in a mesh SFU room. Other values, including whether the plan allows the
+    /// recording type, are left for `Daily` to validate.
+    ///
+    /// This is called when sending a [CreateRoom](crate::room::CreateRoom) or
+    /// [UpdateRoom](crate::room::UpdateRoom) with these properties. See
+    /// [validate_strict](Self::validate_strict) for further checks.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`](crate::Error::InvalidConfig) describing the first problem found.
+    pub fn validate(&self) -> crate::Result<()> {
+        validate_timing(self.nbf, self.exp, self.eject_after_elapsed)?;
+        if self.max_participants == Some(0) {
+            return Err(crate::Error::invalid_config(
+                "max_participants must be at least 1",
+            ));
+        }
+        if let Some(sfu_switchover) = self.sfu_switchover {
+            if sfu_switchover.is_nan() || sfu_switchover < 0. {
+                return Err(crate::Error::invalid_config(format!(
+                    "sfu_switchover ({sfu_switchover}) must be a participant count, or 0.5 to always use SFU"
+                )));
+            }
+        }
+        if let (Some(true), Some(sfu_switchover)) = (self.enable_mesh_sfu, self.sfu_switchover) {
+            return Err(crate::Error::invalid_config(format!(
+                "sfu_switchover ({sfu_switchover}) can't be set with enable_mesh_sfu, which always uses SFU"
+            )));
+        }
+        Ok(())
+    }
+
+    /// [Validate](Self::validate) these properties, and also reject combinations which
+    /// `Daily` documents as incompatible but may still accept:
+    /// [`Local`](RecordingType::Local) recording with `owner_only_broadcast` or a
+    /// `recordings_bucket`.
+    ///
+    /// This is called instead of `validate` when sending a
+    /// [strict](crate::room::CreateRoom::strict) [CreateRoom](crate::room::CreateRoom).
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`](crate::Error::InvalidConfig) describing the first problem found.
+    pub fn validate_strict(&self) -> crate::Result<()> {
+        self.validate()?;
+        if self.enable_recording == Some(RecordingType::Local) {
+            if self.owner_only_broadcast == Some(true) {
+                return Err(crate::Error::invalid_config(
+                    "local recording can't be enabled with owner_only_broadcast",
+                ));
+            }
+            if self.recordings_bucket.is_some() {
+                return Err(crate::Error::invalid_config(
+                    "recordings_bucket can't be set with local recording, which is saved to the participant's device",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RoomPropertiesBuilder<'_> {
+    /// Load room properties from a JSON object, such as a template written by
+    /// [`to_json`](Self::to_json), using the same property names as `Daily`.
+    ///
+    /// Properties without a typed setter are kept as [`extra`](Self::extra) ones.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`](crate::Error::InvalidConfig) if `json` isn't an object
+    /// of room properties.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dailyco::RoomPropertiesBuilder;
+    /// # use dailyco::room::CreateRoom;
+    /// # fn run() -> dailyco::Result<()> {
+    /// let template = RoomPropertiesBuilder::from_json(r#"{"max_participants": 10}"#)?;
+    /// let mut create = CreateRoom::new();
+    /// create.properties(template.as_builder().start_audio_off(true));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_json(json: &str) -> crate::Result<OwnedRoomProperties> {
+        serde_json::from_str(json).map_err(|err| {
+            crate::Error::invalid_config(format!("invalid room properties JSON: {err}"))
+        })
+    }
+
+    /// The properties set on this builder as a JSON object, in the form sent to `Daily`.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Room properties are always serializable")
+    }
+}
+
+/// Room properties which own their data, as loaded by
+/// [`RoomPropertiesBuilder::from_json`] or converted from a [`RoomPropertiesBuilder`].
+///
+/// Unlike a builder they can be kept, e.g. in a configuration struct, and used for
+/// any number of rooms. Use [`as_builder`](Self::as_builder) to send them, or adjust
+/// them further.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dailyco::{Client, OwnedRoomProperties, Result, RoomPropertiesBuilder};
+/// # use dailyco::room::{CreateRoom, Room};
+/// struct RoomDefaults {
+///     properties: OwnedRoomProperties,
+/// }
+///
+/// impl RoomDefaults {
+///     fn new(lobby_hook: &str) -> Self {
+///         let properties = RoomPropertiesBuilder::new()
+///             .max_participants(8)
+///             .meeting_join_hook(lobby_hook);
+///         Self {
+///             properties: properties.into(),
+///         }
+///     }
+///
+///     async fn create(&self, client: &Client, name: &str) -> Result<Room> {
+///         CreateRoom::new()
+///             .name(name)
+///             .properties_owned(&self.properties)
+///             .send(client)
+///             .await
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct OwnedRoomProperties {
+    nbf: Option<i64>,
+    exp: Option<i64>,
+    max_participants: Option<usize>,
+    enable_people_ui: Option<bool>,
+    enable_pip_ui: Option<bool>,
+    enable_prejoin_ui: Option<bool>,
+    enable_network_ui: Option<bool>,
+    enable_knocking: Option<bool>,
+    enable_screenshare: Option<bool>,
+    enable_video_processing_ui: Option<bool>,
+    enable_chat: Option<bool>,
+    enable_advanced_chat: Option<bool>,
+    start_video_off: Option<bool>,
+    start_audio_off: Option<bool>,
+    owner_only_broadcast: Option<bool>,
+    enable_recording: Option<RecordingType>,
+    eject_at_room_exp: Option<bool>,
+    eject_after_elapsed: Option<i64>,
+    enable_hidden_participants: Option<bool>,
+    enable_mesh_sfu: Option<bool>,
+    experimental_optimize_large_calls: Option<bool>,
+    lang: Option<DailyLang>,
+    meeting_join_hook: Option<String>,
+    signaling_imp: Option<SignalingImp>,
+    geo: Option<Region>,
+    rtmp_geo: Option<RtmpGeoRegion>,
+    enable_terse_logging: Option<bool>,
+    recordings_template: Option<String>,
+    recordings_bucket: Option<RecordingsBucket>,
+    auto_start_transcription: Option<bool>,
+    auto_transcription_settings: Option<AutoTranscriptionSettings>,
+    sfu_switchover: Option<f64>,
+    enable_dialin: Option<bool>,
+    sip: Option<SipSettings>,
+    permissions: Option<TokenPermissions>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl OwnedRoomProperties {
+    /// A builder with these properties set, borrowing from `self`.
+    pub fn as_builder(&self) -> RoomPropertiesBuilder<'_> {
+        RoomPropertiesBuilder {
+            nbf: self.nbf,
+            exp: self.exp,
+            max_participants: self.max_participants,
+            enable_people_ui: self.enable_people_ui,
+            enable_pip_ui: self.enable_pip_ui,
+            enable_prejoin_ui: self.enable_prejoin_ui,
+            enable_network_ui: self.enable_network_ui,
+            enable_knocking: self.enable_knocking,
+            enable_screenshare: self.enable_screenshare,
+            enable_video_processing_ui: self.enable_video_processing_ui,
+            enable_chat: self.enable_chat,
+            enable_advanced_chat: self.enable_advanced_chat,
+            start_video_off: self.start_video_off,
+            start_audio_off: self.start_audio_off,
+            owner_only_broadcast: self.owner_only_broadcast,
+            enable_recording: self.enable_recording,
+            eject_at_room_exp: self.eject_at_room_exp,
+            eject_after_elapsed: self.eject_after_elapsed,
+            enable_hidden_participants: self.enable_hidden_participants,
+            enable_mesh_sfu: self.enable_mesh_sfu,
+            experimental_optimize_large_calls: self.experimental_optimize_large_calls,
+            lang: self.lang,
+            meeting_join_hook: self.meeting_join_hook.as_deref(),
+            signaling_imp: self.signaling_imp,
+            geo: self.geo,
+            rtmp_geo: self.rtmp_geo,
+            enable_terse_logging: self.enable_terse_logging,
+            recordings_template: self.recordings_template.as_deref(),
+            recordings_bucket: self.recordings_bucket.as_ref(),
+            auto_start_transcription: self.auto_start_transcription,
+            auto_transcription_settings: self.auto_transcription_settings.as_ref(),
+            sfu_switchover: self.sfu_switchover,
+            enable_dialin: self.enable_dialin,
+            sip: self.sip.as_ref(),
+            permissions: self.permissions.as_ref(),
+            extra: self.extra.clone(),
+        }
+    }
+}
+
+impl From<RoomPropertiesBuilder<'_>> for OwnedRoomProperties {
+    fn from(builder: RoomPropertiesBuilder<'_>) -> Self {
+        let RoomPropertiesBuilder {
+            nbf,
+            exp,
+            max_participants,
+            enable_people_ui,
+            enable_pip_ui,
+            enable_prejoin_ui,
+            enable_network_ui,
+            enable_knocking,
+            enable_screenshare,
+            enable_video_processing_ui,
+            enable_chat,
+            enable_advanced_chat,
+            start_video_off,
+            start_audio_off,
+            owner_only_broadcast,
+            enable_recording,
+            eject_at_room_exp,
+            eject_after_elapsed,
+            enable_hidden_participants,
+            enable_mesh_sfu,
+            experimental_optimize_large_calls,
+            lang,
+            meeting_join_hook,
+            signaling_imp,
+            geo,
+            rtmp_geo,
+            enable_terse_logging,
+            recordings_template,
+            recordings_bucket,
+            auto_start_transcription,
+            auto_transcription_settings,
+            sfu_switchover,
+            enable_dialin,
+            sip,
+            permissions,
+            extra,
+        } = builder;
+        Self {
+            nbf,
+            exp,
+            max_participants,
+            enable_people_ui,
+            enable_pip_ui,
+            enable_prejoin_ui,
+            enable_network_ui,
+            enable_knocking,
+            enable_screenshare,
+            enable_video_processing_ui,
+            enable_chat,
+            enable_advanced_chat,
+            start_video_off,
+            start_audio_off,
+            owner_only_broadcast,
+            enable_recording,
+            eject_at_room_exp,
+            eject_after_elapsed,
+            enable_hidden_participants,
+            enable_mesh_sfu,
+            experimental_optimize_large_calls,
+            lang,
+            meeting_join_hook: meeting_join_hook.map(str::to_string),
+            signaling_imp,
+            geo,
+            rtmp_geo,
+            enable_terse_logging,
+            recordings_template: recordings_template.map(str::to_string),
+            recordings_bucket: recordings_bucket.cloned(),
+            auto_start_transcription,
+            auto_transcription_settings: auto_transcription_settings.cloned(),
+            sfu_switchover,
+            enable_dialin,
+            sip: sip.cloned(),
+            permissions: permissions.cloned(),
+            extra,
+        }
+    }
 }
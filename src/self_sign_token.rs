@@ -1,38 +1,78 @@
-use jsonwebtoken::{encode, EncodingKey, Header};
+use std::time::Duration;
+
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
 
 use crate::configuration::{DailyLang, RecordingType};
-use crate::meeting_token::CreateMeetingToken;
+use crate::meeting_token::{CreateMeetingToken, MeetingToken};
+use crate::utils::whole_secs;
+use crate::Error;
 
-#[derive(serde::Serialize)]
+#[derive(Serialize)]
 struct SelfSigningTokenPayload<'a> {
     // Domain id
     d: &'a str,
     #[serde(flatten)]
-    rest: MeetingTokenRenamed<'a>,
+    rest: MeetingTokenRenamed<&'a str>,
 }
 
 pub fn self_sign_token(config: CreateMeetingToken, domain_id: &str, secret_key: &str) -> String {
+    // `Daily` expects HS256 tokens without a `kid`, the default header, for which
+    // encoding with a secret key cannot fail.
+    self_sign_token_with_header(config, domain_id, secret_key, &Header::default())
+        .expect("Could not construct token")
+}
+
+pub fn self_sign_token_with_header(
+    config: CreateMeetingToken,
+    domain_id: &str,
+    secret_key: &str,
+    header: &Header,
+) -> crate::Result<String> {
     let payload = SelfSigningTokenPayload {
         d: domain_id,
         rest: config.into(),
     };
-    let token = encode(
-        &Header::default(),
+    encode(
+        header,
         &payload,
         &EncodingKey::from_secret(secret_key.as_ref()),
     )
-    // TOOD: Should safe, unless weird secret / domain inputs from user? Worth validation?
-    .expect("Could not construct token");
-    token
+    .map_err(Error::InvalidMeetingToken)
+}
+
+pub fn decode_with_keys(
+    token: &str,
+    secret_keys: &[&str],
+    leeway: Duration,
+) -> crate::Result<MeetingToken> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.algorithms = vec![Algorithm::HS256, Algorithm::HS384, Algorithm::HS512];
+    // Self-signed tokens don't have to expire, but an `exp` which has passed is still checked
+    validation.required_spec_claims.clear();
+    validation.validate_nbf = true;
+    validation.leeway = whole_secs(leeway) as u64;
+    for secret_key in secret_keys {
+        let key = DecodingKey::from_secret(secret_key.as_ref());
+        match decode::<MeetingTokenRenamed<String>>(token, &key, &validation) {
+            Ok(data) => return Ok(data.claims.into()),
+            Err(err) if matches!(err.kind(), ErrorKind::InvalidSignature) => continue,
+            Err(err) => return Err(Error::InvalidMeetingToken(err)),
+        }
+    }
+    Err(Error::MeetingTokenKeyMismatch)
 }
 
 // TODO: very duplicative, but seems not like not a better way when
 // essentially need to rename struct in 2 different ways. Definitely
 // could be cleaner with a proc macro
-#[derive(serde::Serialize, Copy, Clone)]
-struct MeetingTokenRenamed<'a> {
+// Generic over the string type so the same renaming is used to serialize borrowed
+// builder fields and to deserialize owned ones when decoding.
+#[derive(Serialize, Deserialize, Copy, Clone)]
+struct MeetingTokenRenamed<S> {
     #[serde(skip_serializing_if = "Option::is_none", rename = "r")]
-    room_name: Option<&'a str>,
+    room_name: Option<S>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ejt")]
     eject_at_token_exp: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "eje")]
@@ -44,9 +84,9 @@ struct MeetingTokenRenamed<'a> {
     #[serde(skip_serializing_if = "Option::is_none", rename = "o")]
     is_owner: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "u")]
-    user_name: Option<&'a str>,
+    user_name: Option<S>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ud")]
-    user_id: Option<&'a str>,
+    user_id: Option<S>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ss")]
     enable_screenshare: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "vo")]
@@ -64,33 +104,99 @@ struct MeetingTokenRenamed<'a> {
     #[serde(skip_serializing_if = "Option::is_none", rename = "ctoe")]
     close_tab_on_exit: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "rome")]
-    redirect_on_meeting_exit: Option<&'a str>,
+    redirect_on_meeting_exit: Option<S>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "uil")]
     lang: Option<DailyLang>,
 }
 
-// Same as comment above with respect to duplication here being not great
-impl<'a> From<CreateMeetingToken<'a>> for MeetingTokenRenamed<'a> {
+// Same as comment above with respect to duplication here being not great. The builder is
+// destructured so that a new field fails to compile until it is also handled here.
+impl<'a> From<CreateMeetingToken<'a>> for MeetingTokenRenamed<&'a str> {
     fn from(b: CreateMeetingToken<'a>) -> Self {
+        let CreateMeetingToken {
+            room_name,
+            eject_at_token_exp,
+            eject_after_elapsed,
+            nbf,
+            exp,
+            is_owner,
+            user_name,
+            user_id,
+            enable_screenshare,
+            start_video_off,
+            start_audio_off,
+            enable_recording,
+            enable_prejoin_ui,
+            enable_terse_logging,
+            start_cloud_recording,
+            close_tab_on_exit,
+            redirect_on_meeting_exit,
+            lang,
+        } = b;
+        Self {
+            room_name,
+            eject_at_token_exp,
+            eject_after_elapsed,
+            nbf,
+            exp,
+            is_owner,
+            user_name,
+            user_id,
+            enable_screenshare,
+            start_video_off,
+            start_audio_off,
+            enable_recording,
+            enable_prejoin_ui,
+            enable_terse_logging,
+            start_cloud_recording,
+            close_tab_on_exit,
+            redirect_on_meeting_exit,
+            lang,
+        }
+    }
+}
+
+impl From<MeetingTokenRenamed<String>> for MeetingToken {
+    fn from(claims: MeetingTokenRenamed<String>) -> Self {
+        let MeetingTokenRenamed {
+            room_name,
+            eject_at_token_exp,
+            eject_after_elapsed,
+            nbf,
+            exp,
+            is_owner,
+            user_name,
+            user_id,
+            enable_screenshare,
+            start_video_off,
+            start_audio_off,
+            enable_recording,
+            enable_prejoin_ui,
+            enable_terse_logging,
+            start_cloud_recording,
+            close_tab_on_exit,
+            redirect_on_meeting_exit,
+            lang,
+        } = claims;
         Self {
-            room_name: b.room_name,
-            eject_at_token_exp: b.eject_at_token_exp,
-            eject_after_elapsed: b.eject_after_elapsed,
-            nbf: b.nbf,
-            exp: b.exp,
-            is_owner: b.is_owner,
-            user_name: b.user_name,
-            user_id: b.user_id,
-            enable_screenshare: b.enable_screenshare,
-            start_video_off: b.start_video_off,
-            start_audio_off: b.start_audio_off,
-            enable_recording: b.enable_recording,
-            enable_prejoin_ui: b.enable_prejoin_ui,
-            enable_terse_logging: b.enable_terse_logging,
-            start_cloud_recording: b.start_cloud_recording,
-            close_tab_on_exit: b.close_tab_on_exit,
-            redirect_on_meeting_exit: b.redirect_on_meeting_exit,
-            lang: b.lang,
+            room_name,
+            eject_at_token_exp: eject_at_token_exp.unwrap_or_default(),
+            eject_after_elapsed,
+            nbf,
+            exp,
+            is_owner: is_owner.unwrap_or_default(),
+            user_name,
+            user_id,
+            enable_screenshare: enable_screenshare.unwrap_or(true),
+            start_video_off: start_video_off.unwrap_or_default(),
+            start_audio_off: start_audio_off.unwrap_or_default(),
+            enable_recording,
+            enable_prejoin_ui,
+            enable_terse_logging: enable_terse_logging.unwrap_or_default(),
+            start_cloud_recording: start_cloud_recording.unwrap_or_default(),
+            close_tab_on_exit: close_tab_on_exit.unwrap_or_default(),
+            redirect_on_meeting_exit,
+            lang,
         }
     }
 }
@@ -1,3 +1,86 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "strict-deserialize")]
+use serde::{de, Deserialize, Deserializer};
+#[cfg(feature = "strict-deserialize")]
+use serde_json::Map;
+use serde_json::Value;
+
 pub const fn default_as_true() -> bool {
     true
 }
+
+/// The UTC timestamp, in seconds, `duration` from now, saturating at `i64::MAX`.
+pub fn timestamp_in(duration: Duration) -> i64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(since_epoch) => since_epoch
+            .checked_add(duration)
+            .map_or(i64::MAX, floor_secs),
+        // A clock set before the epoch gives an early timestamp rather than a panic. Both
+        // are at most `i64::MAX` and not negative, so this can't overflow.
+        Err(err) => floor_secs(duration) - floor_secs(err.duration()),
+    }
+}
+
+/// `duration` in whole seconds, rounded down, saturating at `i64::MAX`.
+fn floor_secs(duration: Duration) -> i64 {
+    i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)
+}
+
+/// `duration` in whole seconds, rounded up so that a partial second isn't lost.
+pub fn whole_secs(duration: Duration) -> i64 {
+    let secs = duration
+        .as_secs()
+        .saturating_add(u64::from(duration.subsec_nanos() > 0));
+    i64::try_from(secs).unwrap_or(i64::MAX)
+}
+
+/// Check the timing settings shared by rooms and meeting tokens aren't contradictory.
+pub fn validate_timing(
+    nbf: Option<i64>,
+    exp: Option<i64>,
+    eject_after_elapsed: Option<i64>,
+) -> crate::Result<()> {
+    if let (Some(nbf), Some(exp)) = (nbf, exp) {
+        if nbf >= exp {
+            return Err(crate::Error::invalid_config(format!(
+                "nbf ({nbf}) must be before exp ({exp})"
+            )));
+        }
+    }
+    if let Some(eject_after_elapsed) = eject_after_elapsed.filter(|secs| *secs < 0) {
+        return Err(crate::Error::invalid_config(format!(
+            "eject_after_elapsed ({eject_after_elapsed}) must not be negative"
+        )));
+    }
+    Ok(())
+}
+
+/// Deserialize the fields flattened into an `extra` map, failing if there are any.
+///
+/// `deny_unknown_fields` has no effect on a struct with a flattened map, which
+/// accepts every field, so the map rejects them itself.
+#[cfg(feature = "strict-deserialize")]
+pub fn deny_unmodelled<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Map<String, Value>, D::Error> {
+    let extra = Map::deserialize(deserializer)?;
+    match extra.keys().next() {
+        Some(field) => Err(de::Error::custom(format!("unknown field `{field}`"))),
+        None => Ok(extra),
+    }
+}
+
+/// Whether `created` has the `requested` value, ignoring fields `Daily` adds to objects
+/// and how numbers are represented.
+pub fn matches_requested(requested: &Value, created: &Value) -> bool {
+    match (requested, created) {
+        (Value::Object(requested), Value::Object(created)) => requested
+            .iter()
+            .all(|(key, value)| matches_requested(value, created.get(key).unwrap_or(&Value::Null))),
+        (Value::Number(requested), Value::Number(created)) => {
+            requested.as_f64() == created.as_f64()
+        }
+        _ => requested == created,
+    }
+}
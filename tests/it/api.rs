@@ -0,0 +1,28 @@
+use dailyco::room::CreateRoom;
+use dailyco::DailyApi;
+
+use crate::helpers::{json_response, room_json, MockServer};
+
+async fn recreate_room(api: &(impl DailyApi + Sync), name: &str) -> dailyco::Result<String> {
+    api.delete_room_if_exists(name).await?;
+    let room = api.create_room(CreateRoom::new().name(name)).await?;
+    Ok(room.name)
+}
+
+#[tokio::test]
+async fn client_implements_daily_api() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", r#"{"deleted":true,"name":"a-room"}"#),
+        json_response("200 OK", &room_json("room-id", "a-room")),
+    ]);
+    let client = server.client();
+
+    // Spawning requires the futures returned through the trait to be `Send`
+    let name = tokio::spawn(async move { recreate_room(&client, "a-room").await })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(name, "a-room");
+    assert!(server.received().starts_with("DELETE "));
+    assert!(server.received().starts_with("POST "));
+}
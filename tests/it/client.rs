@@ -0,0 +1,301 @@
+use std::sync::{Arc, Mutex};
+
+use dailyco::room::CreateRoom;
+use dailyco::{Client, Error, RequestOptions};
+use reqwest::{Proxy, Url};
+
+use crate::helpers::{json_response, room_json, MockServer};
+
+#[tokio::test]
+async fn requests_go_through_proxy() {
+    let proxy = MockServer::start(vec![json_response(
+        "200 OK",
+        r#"{"total_count":7,"data":[]}"#,
+    )]);
+    let client = Client::builder("test-api-key")
+        .endpoint(Url::parse("http://api.daily.invalid/v1/").unwrap())
+        .proxy(Proxy::http(proxy.endpoint.as_str()).unwrap())
+        .build()
+        .unwrap();
+
+    assert_eq!(client.count_rooms().await.unwrap(), 7);
+    let request = proxy.received();
+    assert!(request.starts_with("GET http://api.daily.invalid/v1/rooms"));
+    assert!(request.contains("authorization: Bearer test-api-key"));
+}
+
+#[test]
+fn client_introspection_never_exposes_key() {
+    let endpoint = Url::parse("http://localhost:8080/").unwrap();
+    let client = Client::with_endpoint("super-secret-key", endpoint.clone()).unwrap();
+    assert_eq!(client.base_url(), &endpoint);
+    assert!(client.has_api_key());
+    assert!(!format!("{client:?}").contains("super-secret-key"));
+    assert!(!format!("{client:#?}").contains("super-secret-key"));
+    assert!(!format!("{:?}", Client::builder("super-secret-key")).contains("super-secret-key"));
+
+    let client = Client::new("").unwrap();
+    assert_eq!(client.base_url().as_str(), "https://api.daily.co/v1/");
+    assert!(!client.has_api_key());
+}
+
+#[tokio::test]
+async fn cloned_clients_share_connections() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+    ]);
+    let client = server.client();
+    let clone = client.clone();
+
+    client.get_room("a-room").await.unwrap();
+    clone.get_room("a-room").await.unwrap();
+
+    assert_eq!(server.connections(), 1);
+}
+
+#[tokio::test]
+async fn request_bodies_are_passed_to_hook() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        &room_json("a-room-id", "a-room"),
+    )]);
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let captured = Arc::clone(&bodies);
+    let client = Client::builder("test-api-key")
+        .endpoint(server.endpoint.clone())
+        .on_request_body(move |method, url, body| {
+            captured
+                .lock()
+                .unwrap()
+                .push(format!("{method} {} {body}", url.path()));
+        })
+        .build()
+        .unwrap();
+
+    CreateRoom::new()
+        .name("a-room")
+        .send(&client)
+        .await
+        .unwrap();
+
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(*bodies, [r#"POST /rooms/ {"name":"a-room"}"#]);
+    assert!(server.received().ends_with(r#"{"name":"a-room"}"#));
+}
+
+#[tokio::test]
+async fn request_errors_never_expose_key() {
+    // Bind then drop a listener, so nothing is listening on the port
+    let endpoint = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap()
+    };
+    let client = Client::with_endpoint("super-secret-key", endpoint).unwrap();
+
+    let err = client.get_room("a-room").await.unwrap_err();
+    assert!(!format!("{err:?}").contains("super-secret-key"));
+    assert!(!err.to_string().contains("super-secret-key"));
+}
+
+#[tokio::test]
+async fn health_check_tells_bad_keys_apart() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", r#"{"total_count":3,"data":[]}"#),
+        json_response(
+            "401 Unauthorized",
+            r#"{"error":"authentication-error","info":"bad key"}"#,
+        ),
+        json_response("500 Internal Server Error", r#"{"error":"server-error"}"#),
+    ]);
+    let client = server.client();
+
+    client.health_check().await.unwrap();
+    assert!(server.received().contains("limit=1"));
+    let err = client.health_check().await.unwrap_err();
+    assert!(err.is_bad_api_key());
+    assert!(
+        matches!(err, Error::APIError(info) if info.info.as_deref() == Some("bad key")),
+        "Daily's error info should be kept"
+    );
+    let err = client.health_check().await.unwrap_err();
+    assert!(!err.is_bad_api_key());
+    assert!(matches!(err, Error::APIError(_)));
+}
+
+#[tokio::test]
+async fn user_agent_is_configurable() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+    ]);
+
+    server.client().get_room("a-room").await.unwrap();
+    let request = server.received();
+    assert!(request.contains(&format!(
+        "user-agent: dailyco-rs/{}\r\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+
+    let client = Client::builder("test-api-key")
+        .endpoint(server.endpoint.clone())
+        .user_agent("my-service/1.2 (staging)")
+        .build()
+        .unwrap();
+    client.get_room("a-room").await.unwrap();
+    assert!(server
+        .received()
+        .contains("user-agent: my-service/1.2 (staging)\r\n"));
+
+    assert!(Client::builder("test-api-key")
+        .user_agent("bad\nagent")
+        .build()
+        .is_err());
+}
+
+#[tokio::test]
+async fn headers_can_be_set_by_default_and_per_request() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+    ]);
+    let client = Client::builder("test-api-key")
+        .endpoint(server.endpoint.clone())
+        .default_header("x-daily-beta", "on")
+        .default_header("x-tenant-id", "default-tenant")
+        .build()
+        .unwrap();
+
+    client
+        .with_options(
+            &RequestOptions::new()
+                .header("x-tenant-id", "tenant-42")
+                .unwrap(),
+        )
+        .get_room("a-room")
+        .await
+        .unwrap();
+    let request = server.received();
+    assert!(request.contains("x-daily-beta: on\r\n"));
+    assert!(request.contains("x-tenant-id: tenant-42\r\n"));
+    assert!(!request.contains("default-tenant"));
+
+    client.get_room("a-room").await.unwrap();
+    assert!(server
+        .received()
+        .contains("x-tenant-id: default-tenant\r\n"));
+
+    let res = RequestOptions::new().header("Authorization", "Bearer other");
+    assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+    let res = Client::builder("test-api-key")
+        .default_header("bad header", "value")
+        .build();
+    assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+}
+
+#[test]
+fn endpoint_can_be_given_as_a_string() {
+    let client = Client::with_endpoint_str("test-api-key", "http://localhost:8080/v1/").unwrap();
+    assert_eq!(client.base_url().as_str(), "http://localhost:8080/v1/");
+
+    match Client::with_endpoint_str("test-api-key", "api.daily.invalid/v1/") {
+        Err(Error::BadEndpoint { endpoint, .. }) => assert_eq!(endpoint, "api.daily.invalid/v1/"),
+        res => panic!("Expected a bad endpoint error, found {res:?}"),
+    }
+}
+
+#[test]
+#[cfg(feature = "metrics")]
+fn request_outcomes_are_counted() {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+    };
+
+    #[derive(Default)]
+    struct Count(AtomicU64);
+
+    impl CounterFn for Count {
+        fn increment(&self, value: u64) {
+            self.0.fetch_add(value, Ordering::SeqCst);
+        }
+
+        fn absolute(&self, value: u64) {
+            self.0.store(value, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Default)]
+    struct Counts(Mutex<BTreeMap<String, Arc<Count>>>);
+
+    impl Recorder for Counts {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let labels: Vec<_> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let name = format!("{}{{{}}}", key.name(), labels.join(","));
+            let count = Arc::clone(self.0.lock().unwrap().entry(name).or_default());
+            Counter::from_arc(count)
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("429 Too Many Requests", r#"{"error":"rate-limit-error"}"#),
+        json_response("404 Not Found", r#"{"error":"not-found"}"#),
+    ]);
+    let client = server.client();
+    let recorder = Counts::default();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    metrics::with_local_recorder(&recorder, || {
+        runtime.block_on(async {
+            client.get_room("a-room").await.unwrap();
+            client.get_room("another-room").await.unwrap();
+            client.get_room("a-room").await.unwrap_err();
+            client.delete_room("a-room").await.unwrap_err();
+        })
+    });
+
+    let counts: BTreeMap<_, _> = recorder
+        .0
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|(name, count)| (name, count.0.load(Ordering::SeqCst)))
+        .collect();
+    let expected = BTreeMap::from([
+        (
+            "dailyco_requests_total{method=DELETE,endpoint=rooms/:id,outcome=error}".to_string(),
+            1,
+        ),
+        (
+            "dailyco_requests_total{method=GET,endpoint=rooms/:id,outcome=rate_limited}"
+                .to_string(),
+            1,
+        ),
+        (
+            "dailyco_requests_total{method=GET,endpoint=rooms/:id,outcome=success}".to_string(),
+            2,
+        ),
+    ]);
+    assert_eq!(counts, expected);
+}
@@ -0,0 +1,190 @@
+use dailyco::configuration::{AwsRegion, DailyLang, RecordingsBucket, Region, RtmpGeoRegion};
+use dailyco::Error;
+use serde_json::json;
+
+const ROLE_ARN: &str = "arn:aws:iam::123456789012:role/daily-recordings";
+
+#[test]
+fn recordings_bucket_builder() {
+    let bucket = RecordingsBucket::builder()
+        .bucket_name("my.recordings-bucket")
+        .bucket_region("eu-west-2")
+        .assume_role_arn(ROLE_ARN)
+        .allow_api_access(true)
+        .build()
+        .unwrap();
+    assert_eq!(
+        bucket,
+        RecordingsBucket {
+            bucket_name: "my.recordings-bucket".to_string(),
+            bucket_region: AwsRegion::EuWest2,
+            assume_role_arn: ROLE_ARN.to_string(),
+            allow_api_access: true,
+            allow_streaming_from_bucket: None,
+        }
+    );
+}
+
+#[test]
+fn recordings_bucket_builder_rejects_invalid_values() {
+    let cases = [
+        ("Recordings", "eu-west-2", ROLE_ARN),
+        ("ab", "eu-west-2", ROLE_ARN),
+        ("recordings", "eu-west", ROLE_ARN),
+        (
+            "recordings",
+            "eu-west-2",
+            "arn:aws:iam::123456789012:user/me",
+        ),
+        ("recordings", "eu-west-2", "arn:aws:iam::1234:role/daily"),
+        ("recordings", "eu-west-2", "arn:aws:s3:::recordings"),
+        ("recordings", "eu-west-2", "daily-recordings"),
+    ];
+    for (name, region, arn) in cases {
+        let res = RecordingsBucket::builder()
+            .bucket_name(name)
+            .bucket_region(region)
+            .assume_role_arn(arn)
+            .build();
+        assert!(
+            matches!(res, Err(Error::InvalidConfig { .. })),
+            "{name}, {region}, {arn} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn recordings_bucket_builder_requires_values() {
+    let res = RecordingsBucket::builder()
+        .bucket_name("recordings")
+        .assume_role_arn(ROLE_ARN)
+        .build();
+    let Err(Error::InvalidConfig { reason }) = res else {
+        panic!("Missing region should be rejected");
+    };
+    assert!(reason.contains("bucket_region"));
+}
+
+#[test]
+fn aws_regions_round_trip_through_their_aws_names() {
+    let regions = [
+        (AwsRegion::EuWest2, "eu-west-2"),
+        (AwsRegion::UsEast1, "us-east-1"),
+        (AwsRegion::ApSoutheast4, "ap-southeast-4"),
+        (AwsRegion::UsGovWest1, "us-gov-west-1"),
+        (AwsRegion::CnNorthwest1, "cn-northwest-1"),
+        (AwsRegion::Other("mars-north-1".to_string()), "mars-north-1"),
+    ];
+    for (region, name) in regions {
+        assert_eq!(serde_json::to_value(&region).unwrap(), json!(name));
+        assert_eq!(
+            serde_json::from_value::<AwsRegion>(json!(name)).unwrap(),
+            region
+        );
+        assert_eq!(AwsRegion::from(name), region);
+        assert_eq!(region.to_string(), name);
+    }
+    assert!(AwsRegion::EuWest2.is_known());
+    assert!(!AwsRegion::from("eu-west").is_known());
+
+    let bucket = RecordingsBucket::builder()
+        .bucket_name("recordings")
+        .bucket_region(AwsRegion::MeCentral1)
+        .assume_role_arn(ROLE_ARN)
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&bucket).unwrap()["bucket_region"],
+        "me-central-1"
+    );
+}
+
+#[test]
+fn region_for_country() {
+    assert_eq!(Region::for_country("DE"), Some(Region::EuCentral1));
+    assert_eq!(Region::for_country("gb"), Some(Region::EuWest2));
+    assert_eq!(Region::for_country("Jp"), Some(Region::ApNortheast2));
+    assert_eq!(Region::for_country("AU"), Some(Region::ApSoutheast2));
+    assert_eq!(Region::for_country("US"), Some(Region::UsEast1));
+    assert_eq!(Region::for_country("XX"), None);
+    assert_eq!(Region::for_country(""), None);
+}
+
+#[test]
+fn regions_parse_from_their_serde_names() {
+    let regions = [
+        Region::AfSouth1,
+        Region::ApNortheast2,
+        Region::ApSoutheast1,
+        Region::ApSoutheast2,
+        Region::ApSouth1,
+        Region::EuCentral1,
+        Region::EuWest2,
+        Region::SaEast1,
+        Region::UsEast1,
+        Region::UsWest2,
+    ];
+    for region in regions {
+        assert_eq!(serde_json::to_value(region).unwrap(), region.as_str());
+        assert_eq!(region.as_str().parse::<Region>(), Ok(region));
+    }
+    for region in [
+        RtmpGeoRegion::UsWest2,
+        RtmpGeoRegion::EuCentral1,
+        RtmpGeoRegion::ApSoutheast1,
+    ] {
+        assert_eq!(serde_json::to_value(region).unwrap(), region.as_str());
+        assert_eq!(region.to_string().parse::<RtmpGeoRegion>(), Ok(region));
+    }
+
+    assert_eq!(serde_json::to_value(Region::EuWest2).unwrap(), "eu-west-2");
+    assert_eq!(
+        serde_json::from_value::<Region>("af-south-1".into()).unwrap(),
+        Region::AfSouth1
+    );
+
+    let err = "us-east-1".parse::<RtmpGeoRegion>().unwrap_err();
+    assert_eq!(err.to_string(), "`us-east-1` is not a known RtmpGeoRegion");
+    assert!("moon-base-1".parse::<Region>().is_err());
+}
+
+#[test]
+fn daily_lang_roundtrip() {
+    let langs = [
+        (DailyLang::Da, "da"),
+        (DailyLang::De, "de"),
+        (DailyLang::En, "en"),
+        (DailyLang::Es, "es"),
+        (DailyLang::Fi, "fi"),
+        (DailyLang::Fr, "fr"),
+        (DailyLang::It, "it"),
+        (DailyLang::Jp, "jp"),
+        (DailyLang::Ka, "ka"),
+        (DailyLang::Nl, "nl"),
+        (DailyLang::No, "no"),
+        (DailyLang::Pt, "pt"),
+        (DailyLang::PtBr, "pt-BR"),
+        (DailyLang::Pl, "pl"),
+        (DailyLang::Ru, "ru"),
+        (DailyLang::Sv, "sv"),
+        (DailyLang::Tr, "tr"),
+        (DailyLang::User, "user"),
+    ];
+    for (lang, code) in langs {
+        assert_eq!(serde_json::to_value(lang).unwrap(), json!(code));
+        assert_eq!(
+            serde_json::from_value::<DailyLang>(json!(code)).unwrap(),
+            lang
+        );
+    }
+    assert_eq!(
+        serde_json::from_value::<DailyLang>(json!("ja")).unwrap(),
+        DailyLang::Jp
+    );
+    assert_eq!(DailyLang::all().len(), langs.len());
+    for lang in DailyLang::all() {
+        assert_eq!(lang.as_str().parse::<DailyLang>(), Ok(*lang));
+    }
+    assert_eq!("ja".parse::<DailyLang>(), Ok(DailyLang::Jp));
+    assert!("xx".parse::<DailyLang>().is_err());
+}
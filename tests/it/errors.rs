@@ -0,0 +1,224 @@
+use std::error::Error as _;
+use std::time::Duration;
+
+use dailyco::{Client, DailyCoErrorInfo, DailyCoErrorKind, Error, InvalidRequestDetail};
+
+use crate::helpers::{json_response, response, room_json, MockServer};
+
+#[test]
+fn api_error_exposes_info_as_source() {
+    let info = DailyCoErrorInfo {
+        error: Some(DailyCoErrorKind::NotFound),
+        info: Some("room not-found not found".to_string()),
+        request_id: None,
+    };
+    let err = Error::from(info);
+
+    let source = err
+        .source()
+        .expect("API error should have a source")
+        .downcast_ref::<DailyCoErrorInfo>()
+        .expect("Source should be the Daily error info");
+    assert_eq!(source.error, Some(DailyCoErrorKind::NotFound));
+    assert_eq!(source.info.as_deref(), Some("room not-found not found"));
+    assert_eq!(err.to_string(), "daily request returned an error");
+    assert!(source.to_string().contains("room not-found not found"));
+}
+
+#[tokio::test]
+async fn unexpected_response_body_is_preserved() {
+    let server = MockServer::start(vec![json_response("200 OK", r#"{"unexpected":true}"#)]);
+
+    match server.client().get_room("a-room").await {
+        Err(Error::Deserialize { body, .. }) => assert_eq!(body, r#"{"unexpected":true}"#),
+        res => panic!("Expected deserialization error, found {:?}", res),
+    }
+}
+
+fn response_with_request_id(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\ncontent-type: application/json\r\nx-request-id: req-123\r\ncontent-length: {}\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+#[tokio::test]
+async fn request_id_is_captured_from_failed_requests() {
+    let server = MockServer::start(vec![
+        response_with_request_id("500 Internal Server Error", r#"{"error":"server-error"}"#),
+        response_with_request_id("200 OK", r#"{"unexpected":true}"#),
+    ]);
+    let client = server.client();
+
+    let err = client.get_room("a-room").await.unwrap_err();
+    assert_eq!(err.request_id(), Some("req-123"));
+    let Error::APIError(info) = &err else {
+        panic!("Expected API error, found {:?}", err);
+    };
+    assert!(info.to_string().contains("request id: req-123"));
+
+    let err = client.get_room("a-room").await.unwrap_err();
+    assert!(matches!(err, Error::Deserialize { .. }));
+    assert_eq!(err.request_id(), Some("req-123"));
+}
+
+#[tokio::test]
+async fn request_id_is_returned_with_successful_responses() {
+    let server = MockServer::start(vec![
+        response_with_request_id("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+    ]);
+    let client = server.client();
+
+    let room = client
+        .with_request_id(|client| async move { client.get_room("a-room").await })
+        .await
+        .unwrap();
+    assert_eq!(room.value.name, "a-room");
+    assert_eq!(room.request_id.as_deref(), Some("req-123"));
+
+    let room = client
+        .with_request_id(|client| async move { client.get_room("a-room").await })
+        .await
+        .unwrap();
+    assert_eq!(room.request_id, None);
+}
+
+#[tokio::test]
+async fn request_errors_are_classified() {
+    // Bind then drop a listener, so nothing is listening on the port
+    let endpoint = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}/", listener.local_addr().unwrap())
+    };
+    let client = Client::with_endpoint("test-api-key", endpoint.parse().unwrap()).unwrap();
+    let err = client.get_room("a-room").await.unwrap_err();
+    assert!(err.is_connect());
+    assert!(!err.is_timeout());
+    assert!(!err.is_body());
+
+    let server = MockServer::start(vec![json_response("200 OK", r#"{"unexpected":true}"#)]);
+    let err = server.client().get_room("a-room").await.unwrap_err();
+    assert!(err.is_body());
+    assert!(!err.is_connect());
+
+    assert!(Error::Timeout(Duration::from_secs(1)).is_timeout());
+}
+
+#[tokio::test]
+async fn unknown_error_kinds_are_kept() {
+    let server = MockServer::start(vec![json_response(
+        "400 Bad Request",
+        r#"{"error":"brand-new-error","info":"something new"}"#,
+    )]);
+
+    match server.client().get_room("a-room").await {
+        Err(Error::APIError(info)) => {
+            let kind = info.error.expect("No error information");
+            assert_eq!(
+                kind,
+                DailyCoErrorKind::Unknown("brand-new-error".to_string())
+            );
+            assert_eq!(kind.to_string(), "brand-new-error");
+            assert_eq!(serde_json::to_value(&kind).unwrap(), "brand-new-error");
+        }
+        res => panic!("Expected API error, found {:?}", res),
+    }
+    let known: DailyCoErrorKind = serde_json::from_str(r#""rate-limit-error""#).unwrap();
+    assert_eq!(known, DailyCoErrorKind::RateLimitError);
+}
+
+#[test]
+fn invalid_request_info_is_parsed() {
+    let detail = |kind: DailyCoErrorKind, info: &str| {
+        DailyCoErrorInfo {
+            error: Some(kind),
+            info: Some(info.to_string()),
+            request_id: None,
+        }
+        .invalid_request_detail()
+    };
+    let invalid = |info| detail(DailyCoErrorKind::InvalidRequestError, info);
+
+    assert_eq!(
+        invalid("a room named a-room already exists"),
+        Some(InvalidRequestDetail::NameTaken)
+    );
+    assert_eq!(
+        invalid("exp must be in the future"),
+        Some(InvalidRequestDetail::ExpInPast)
+    );
+    assert_eq!(
+        invalid("missing required parameter: room_name"),
+        Some(InvalidRequestDetail::MissingField("room_name".to_string()))
+    );
+    assert_eq!(
+        invalid("'properties.exp' is required"),
+        Some(InvalidRequestDetail::MissingField(
+            "properties.exp".to_string()
+        ))
+    );
+    assert_eq!(
+        invalid("nbf must be before exp"),
+        Some(InvalidRequestDetail::Other(
+            "nbf must be before exp".to_string()
+        ))
+    );
+    assert_eq!(detail(DailyCoErrorKind::NotFound, "already exists"), None);
+}
+
+#[tokio::test]
+async fn non_json_success_responses_are_reported() {
+    let page = format!("<html>{}</html>", "é".repeat(300));
+    let server = MockServer::start(vec![
+        response("200 OK", "text/html; charset=utf-8", &page),
+        response(
+            "200 OK",
+            "application/json; charset=utf-8",
+            &room_json("a-room-id", "a-room"),
+        ),
+    ]);
+    let client = server.client();
+
+    let err = client.get_room("a-room").await.unwrap_err();
+    assert!(err.is_body());
+    match err {
+        Error::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } => {
+            assert_eq!(content_type, "text/html; charset=utf-8");
+            assert_eq!(body_snippet.chars().count(), 200);
+            assert!(page.starts_with(&body_snippet));
+        }
+        err => panic!("Expected an unexpected content type error, found {err:?}"),
+    }
+    client.get_room("a-room").await.unwrap();
+}
+
+#[tokio::test]
+async fn rate_limits_without_a_json_body_are_recognised() {
+    let server = MockServer::start(vec![
+        response(
+            "429 Too Many Requests",
+            "text/html",
+            "<html>Slow down</html>",
+        ),
+        response("429 Too Many Requests", "text/plain", ""),
+        response("502 Bad Gateway", "text/html", "<html>Bad gateway</html>"),
+    ]);
+    let client = server.client();
+
+    for _ in 0..2 {
+        match client.get_room("a-room").await {
+            Err(Error::APIError(info)) => {
+                assert_eq!(info.error, Some(DailyCoErrorKind::RateLimitError))
+            }
+            res => panic!("Expected a rate limit error, found {res:?}"),
+        }
+    }
+    assert!(matches!(
+        client.get_room("a-room").await,
+        Err(Error::Request(_))
+    ));
+}
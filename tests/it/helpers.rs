@@ -1,7 +1,14 @@
 use std::fmt::Debug;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
 
 use dailyco::room::{CreateRoom, Room};
 use dailyco::{Client, DailyCoErrorKind, Error};
+use reqwest::Url;
 
 pub fn get_secret_key_for_tests() -> String {
     dotenv::dotenv().unwrap();
@@ -43,3 +50,114 @@ pub async fn cleanup_room(client: &Client, room_name: &str) {
 pub async fn create_default_room(client: &Client) -> Room {
     CreateRoom::new().send(client).await.unwrap()
 }
+
+/// A local stand-in for the `Daily` API, answering the requests it receives with the
+/// given raw HTTP responses, in order.
+pub struct MockServer {
+    pub endpoint: Url,
+    requests: Receiver<String>,
+    connections: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<String>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let (sender, requests) = mpsc::channel();
+        let responses = Arc::new(Mutex::new(responses.into_iter()));
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = Arc::clone(&connections);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let sender = sender.clone();
+                let responses = Arc::clone(&responses);
+                thread::spawn(move || {
+                    let mut stream = BufReader::new(stream.unwrap());
+                    while let Some(request) = read_request(&mut stream) {
+                        let Some(response) = responses.lock().unwrap().next() else {
+                            return;
+                        };
+                        let _ = sender.send(request);
+                        stream.get_mut().write_all(response.as_bytes()).unwrap();
+                    }
+                });
+            }
+        });
+        Self {
+            endpoint,
+            requests,
+            connections,
+        }
+    }
+
+    pub fn client(&self) -> Client {
+        Client::with_endpoint("test-api-key", self.endpoint.clone()).expect("Should make client")
+    }
+
+    /// The next raw request received by the server.
+    pub fn received(&self) -> String {
+        self.requests.recv().expect("No request received")
+    }
+
+    /// The number of connections opened to the server so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+fn read_request(stream: &mut BufReader<TcpStream>) -> Option<String> {
+    let mut request = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if stream.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+        request.push_str(&line);
+        if line == "\r\n" {
+            break;
+        }
+    }
+    let mut body = vec![0; content_length];
+    stream.read_exact(&mut body).ok()?;
+    request.push_str(&String::from_utf8(body).unwrap());
+    Some(request)
+}
+
+pub fn response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+pub fn json_response(status: &str, body: &str) -> String {
+    response(status, "application/json", body)
+}
+
+pub fn room_json(id: &str, name: &str) -> String {
+    format!(
+        r#"{{"id":"{id}","name":"{name}","api_created":true,"privacy":"public","url":"https://your-domain.daily.co/{name}","created_at":"2019-01-26T09:01:22.000Z","config":{{}}}}"#
+    )
+}
+
+pub fn presence_json(room: &str, participants: usize) -> String {
+    let data: Vec<_> = (0..participants)
+        .map(|i| {
+            format!(
+                r#"{{"room":"{room}","id":"{}","userId":null,"userName":"user-{i}","joinTime":"2023-01-01T20:53:19.000Z","duration":{i}}}"#,
+                uuid::Uuid::new_v4()
+            )
+        })
+        .collect();
+    format!(
+        r#"{{"total_count":{participants},"data":[{}]}}"#,
+        data.join(",")
+    )
+}
@@ -0,0 +1,39 @@
+use dailyco::logs::MeetingLogs;
+use uuid::Uuid;
+
+use crate::helpers::{json_response, MockServer};
+
+#[tokio::test]
+async fn meeting_logs_are_requested_by_session() {
+    let session = Uuid::new_v4();
+    let user = Uuid::new_v4();
+    let body = format!(
+        r#"{{
+            "logs": [{{"time":"2023-01-01T20:53:19.000Z","mtgSessionId":"{session}","userSessionId":"{user}","level":1,"message":"joined","code":"join"}}],
+            "metrics": [{{"mtgSessionId":"{session}","userSessionId":"{user}","videoRecvPacketLoss":0.25,"roundTripTime":0.1,"freezeDuration":2}}]
+        }}"#
+    );
+    let server = MockServer::start(vec![json_response("200 OK", &body)]);
+    let client = server.client();
+
+    let logs = client.get_meeting_logs(session).await.unwrap();
+    assert!(server.received().starts_with(&format!(
+        "GET /logs?mtgSessionId={session}&includeLogs=true&includeMetrics=true "
+    )));
+
+    let [log] = &logs.logs[..] else {
+        panic!("Expected one log line, found {:?}", logs.logs);
+    };
+    assert_eq!(log.message.as_deref(), Some("joined"));
+    assert_eq!(log.user_session_id, Some(user));
+    assert_eq!(log.extra["code"], "join");
+    let [sample] = &logs.metrics[..] else {
+        panic!("Expected one metrics sample, found {:?}", logs.metrics);
+    };
+    assert_eq!(sample.video_recv_packet_loss, Some(0.25));
+    assert_eq!(sample.video_send_packet_loss, None);
+    assert_eq!(sample.extra["freezeDuration"], 2);
+
+    let empty: MeetingLogs = serde_json::from_str("{}").unwrap();
+    assert_eq!(empty, MeetingLogs::default());
+}
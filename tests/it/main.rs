@@ -1,4 +1,13 @@
+// The room properties wire format test builds one large `json!` literal
+#![recursion_limit = "256"]
+
+pub mod api;
+pub mod client;
+pub mod configuration;
+pub mod errors;
 pub mod helpers;
+pub mod logs;
 pub mod recordings;
+pub mod room_properties;
 pub mod rooms;
 pub mod tokens;
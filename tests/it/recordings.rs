@@ -1,7 +1,13 @@
-use dailyco::recording::{GetRecordingAccessLink, ListRecordings};
+use std::time::Duration;
+
+use dailyco::recording::{
+    GetRecordingAccessLink, ListRecordings, RecordingAccessLink, RecordingObject, RecordingStatus,
+};
+use dailyco::Error;
+use futures_util::{StreamExt, TryStreamExt};
 use uuid::Uuid;
 
-use crate::helpers::{assert_not_found_err, get_daily_client};
+use crate::helpers::{assert_not_found_err, get_daily_client, json_response, response, MockServer};
 
 #[tokio::test]
 async fn get_recording_not_found() {
@@ -38,3 +44,384 @@ async fn list_recordings() -> dailyco::Result<()> {
     let _ = ListRecordings::new().limit(20).send(&client).await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn list_recordings_stream() -> dailyco::Result<()> {
+    let client = get_daily_client();
+
+    // Small pages so that streaming a few recordings crosses a page boundary
+    let recordings: Vec<_> = ListRecordings::new()
+        .limit(2)
+        .into_stream(&client)
+        .take(5)
+        .try_collect()
+        .await?;
+    assert!(recordings.len() <= 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_recordings_for_room_respects_max() {
+    let ids = [Uuid::new_v4(), Uuid::new_v4()];
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        &format!(
+            r#"{{"total_count":2,"data":[{},{}]}}"#,
+            recording_json(ids[0]),
+            recording_json(ids[1])
+        ),
+    )]);
+
+    let recordings = server
+        .client()
+        .get_recordings_for_room("a-room", Some(1))
+        .await
+        .unwrap();
+    assert_eq!(recordings.len(), 1);
+    assert_eq!(recordings[0].id, ids[0].into());
+    assert!(server.received().contains("room_name=a-room"));
+}
+
+fn recording_json(id: Uuid) -> String {
+    recording_json_with_status(id, "finished")
+}
+
+fn recording_json_with_status(id: Uuid, status: &str) -> String {
+    format!(
+        r#"{{"id":"{id}","room_name":"a-room","start_ts":1,"status":"{status}","max_participants":2,"duration":3,"s3key":"key","mtgSessionId":"{}"}}"#,
+        Uuid::new_v4()
+    )
+}
+
+#[tokio::test]
+async fn list_recordings_stream_follows_cursor() {
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let server = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":3,"data":[{},{}]}}"#,
+                recording_json(ids[0]),
+                recording_json(ids[1])
+            ),
+        ),
+        json_response(
+            "200 OK",
+            &format!(r#"{{"total_count":3,"data":[{}]}}"#, recording_json(ids[2])),
+        ),
+    ]);
+    let client = server.client();
+
+    let recordings: Vec<_> = ListRecordings::new()
+        .limit(2)
+        .room_name("a-room")
+        .into_stream(&client)
+        .try_collect()
+        .await
+        .unwrap();
+    let received: Vec<Uuid> = recordings
+        .iter()
+        .map(|recording| recording.id.into())
+        .collect();
+    assert_eq!(received, ids);
+
+    let first_request = server.received();
+    assert!(!first_request.contains("starting_after"));
+    let second_request = server.received();
+    assert!(second_request.contains(&format!("starting_after={}", ids[1])));
+    assert!(second_request.contains("room_name=a-room"));
+    assert!(second_request.contains("limit=2"));
+}
+
+#[tokio::test]
+async fn download_recording_without_api_key() {
+    let storage = MockServer::start(vec![response("200 OK", "video/mp4", "mp4-bytes")]);
+    let download_link = storage
+        .endpoint
+        .join("recording.mp4?signature=abc")
+        .unwrap();
+    let api = MockServer::start(vec![json_response(
+        "200 OK",
+        &format!(r#"{{"download_link":"{download_link}","expires":1}}"#),
+    )]);
+
+    let mp4 = api
+        .client()
+        .download_recording(Uuid::new_v4())
+        .await
+        .unwrap();
+    assert_eq!(&mp4[..], b"mp4-bytes");
+
+    assert!(api
+        .received()
+        .contains("authorization: Bearer test-api-key"));
+    let download_request = storage.received();
+    assert!(download_request.starts_with("GET /recording.mp4?signature=abc "));
+    assert!(!download_request.to_lowercase().contains("authorization"));
+}
+
+#[tokio::test]
+async fn download_expired_recording_link() {
+    let storage = MockServer::start(vec![response(
+        "403 Forbidden",
+        "application/xml",
+        "<Error><Code>AccessDenied</Code></Error>",
+    )]);
+    let link: RecordingAccessLink = serde_json::from_str(&format!(
+        r#"{{"download_link":"{}","expires":1234}}"#,
+        storage.endpoint.join("recording.mp4").unwrap()
+    ))
+    .unwrap();
+
+    let res = link.download_bytes(&storage.client()).await;
+    assert!(matches!(
+        res,
+        Err(Error::RecordingLinkExpired { expires: 1234 })
+    ));
+}
+
+#[test]
+fn access_link_expiry() {
+    let now = chrono::Utc::now().timestamp();
+    let link = |expires: i64| RecordingAccessLink {
+        download_link: "https://example.com/recording.mp4".to_string(),
+        expires,
+    };
+
+    assert!(link(now - 1).is_expired());
+    assert!(!link(now + 3600).is_expired());
+    #[cfg(feature = "chrono")]
+    assert_eq!(link(now).expires_datetime().unwrap().timestamp(), now);
+
+    let cached = serde_json::to_string(&link(now + 3600)).unwrap();
+    let cached: RecordingAccessLink = serde_json::from_str(&cached).unwrap();
+    assert_eq!(cached.expires, now + 3600);
+    assert!(!cached.is_expired());
+}
+
+#[test]
+fn recording_end_is_start_plus_duration() {
+    let finished: RecordingObject = serde_json::from_str(&recording_json(Uuid::new_v4())).unwrap();
+    assert_eq!(finished.end_ts(), Some(4));
+    #[cfg(feature = "chrono")]
+    assert_eq!(finished.end_datetime().unwrap().timestamp(), 4);
+
+    let in_progress =
+        recording_json_with_status(Uuid::new_v4(), "in-progress").replace(r#""duration":3,"#, "");
+    let in_progress: RecordingObject = serde_json::from_str(&in_progress).unwrap();
+    assert_eq!(in_progress.end_ts(), None);
+    #[cfg(feature = "chrono")]
+    assert_eq!(in_progress.end_datetime(), None);
+}
+
+#[test]
+fn unknown_recording_statuses_are_tolerated() {
+    for status in ["uploading", "post-processing"] {
+        let recording: RecordingObject =
+            serde_json::from_str(&recording_json_with_status(Uuid::new_v4(), status)).unwrap();
+        assert_eq!(recording.status, RecordingStatus::Unknown);
+        assert!(!recording.status.is_terminal());
+    }
+}
+
+#[test]
+fn recording_status_display_matches_serde() {
+    for (status, terminal) in [
+        (RecordingStatus::Finished, true),
+        (RecordingStatus::InProgress, false),
+        (RecordingStatus::Canceled, true),
+        (RecordingStatus::Failed, true),
+        (RecordingStatus::Unknown, false),
+    ] {
+        assert_eq!(
+            serde_json::to_value(status).unwrap(),
+            serde_json::Value::String(status.to_string())
+        );
+        assert_eq!(status.is_terminal(), terminal);
+    }
+}
+
+#[tokio::test]
+async fn poll_recording_until_ready() {
+    let id = Uuid::new_v4();
+    let server = MockServer::start(vec![
+        json_response("200 OK", &recording_json_with_status(id, "in-progress")),
+        json_response("200 OK", &recording_json_with_status(id, "in-progress")),
+        json_response("200 OK", &recording_json_with_status(id, "finished")),
+    ]);
+
+    let recording = server
+        .client()
+        .poll_recording_until_ready(id, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(recording.status, RecordingStatus::Finished);
+    for _ in 0..3 {
+        assert!(server.received().contains(&format!("/recordings/{id} ")));
+    }
+}
+
+#[tokio::test]
+async fn poll_recording_until_ready_stops_on_failure() {
+    let id = Uuid::new_v4();
+    let server = MockServer::start(vec![
+        json_response("200 OK", &recording_json_with_status(id, "in-progress")),
+        json_response("200 OK", &recording_json_with_status(id, "failed")),
+    ]);
+
+    let recording = server
+        .client()
+        .poll_recording_until_ready(id, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(recording.status, RecordingStatus::Failed);
+}
+
+#[tokio::test]
+async fn poll_recording_until_ready_times_out() {
+    let id = Uuid::new_v4();
+    let in_progress = json_response("200 OK", &recording_json_with_status(id, "in-progress"));
+    let server = MockServer::start(vec![in_progress; 100]);
+
+    let res = server
+        .client()
+        .poll_recording_until_ready(id, Duration::from_millis(10), Duration::from_millis(50))
+        .await;
+    assert!(matches!(res, Err(Error::Timeout(timeout)) if timeout == Duration::from_millis(50)));
+}
+
+#[tokio::test]
+async fn recordings_can_be_requested_by_raw_id() {
+    let id = Uuid::new_v4();
+    let server = MockServer::start(vec![
+        json_response("200 OK", &recording_json(id)),
+        json_response("200 OK", r#"{"deleted":true}"#),
+    ]);
+    let client = server.client();
+
+    let recording = client.get_recording_by_id(&id.to_string()).await.unwrap();
+    assert_eq!(*recording.id.as_uuid(), id);
+    assert!(server
+        .received()
+        .starts_with(&format!("GET /recordings/{id} ")));
+
+    client
+        .delete_recording_by_id("legacy-recording-1")
+        .await
+        .unwrap();
+    assert!(server
+        .received()
+        .starts_with("DELETE /recordings/legacy-recording-1 "));
+}
+
+#[tokio::test]
+async fn raw_recording_ids_stay_in_their_path_segment() {
+    let cases = [
+        ("../rooms", "..%2Frooms"),
+        (
+            "https://evil.example/steal",
+            "https:%2F%2Fevil.example%2Fsteal",
+        ),
+        ("//evil.example/x", "%2F%2Fevil.example%2Fx"),
+        ("a?b#c", "a%3Fb%23c"),
+    ];
+    let server = MockServer::start(
+        cases
+            .iter()
+            .map(|_| json_response("200 OK", r#"{"deleted":true}"#))
+            .collect(),
+    );
+    let client = server.client();
+
+    for (id, path) in cases {
+        client.delete_recording_by_id(id).await.unwrap();
+        let request = server.received();
+        assert!(
+            request.starts_with(&format!("DELETE /recordings/{path} HTTP")),
+            "`{id}` was sent as {}",
+            request.lines().next().unwrap()
+        );
+    }
+
+    for id in ["", ".", ".."] {
+        let res = client.get_recording_by_id(id).await;
+        assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+    }
+}
+
+#[tokio::test]
+async fn access_links_report_whether_they_stream() {
+    let api = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            r#"{"download_link":"https://bucket.s3.amazonaws.com/r.mp4?response-content-disposition=attachment%3B%20filename%3D%22r.mp4%22&X-Amz-Signature=abc","expires":1}"#,
+        ),
+        json_response(
+            "200 OK",
+            r#"{"download_link":"https://bucket.s3.amazonaws.com/r.mp4?response-content-disposition=inline&X-Amz-Signature=abc","expires":1}"#,
+        ),
+    ]);
+    let client = api.client();
+    let request = GetRecordingAccessLink::new();
+
+    let download = request.send(&client, Uuid::new_v4()).await.unwrap();
+    assert_eq!(
+        download.content_disposition().as_deref(),
+        Some(r#"attachment; filename="r.mp4""#)
+    );
+    assert!(!download.is_streamable());
+
+    let stream = request.send(&client, Uuid::new_v4()).await.unwrap();
+    assert_eq!(stream.content_disposition().as_deref(), Some("inline"));
+    assert!(stream.is_streamable());
+}
+
+#[tokio::test]
+async fn access_links_for_finished_recordings() {
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let server = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":3,"data":[{},{},{}]}}"#,
+                recording_json(ids[0]),
+                recording_json_with_status(ids[1], "in-progress"),
+                recording_json(ids[2])
+            ),
+        ),
+        json_response(
+            "200 OK",
+            r#"{"download_link":"https://bucket.s3.amazonaws.com/0.mp4","expires":1}"#,
+        ),
+        json_response(
+            "404 Not Found",
+            r#"{"error":"not-found","info":"recording not found"}"#,
+        ),
+    ]);
+    let client = server.client();
+    let mut link = GetRecordingAccessLink::new();
+    link.valid_for_secs(60);
+
+    let mut links: Vec<_> = ListRecordings::new()
+        .access_links(&client, link, 1)
+        .collect()
+        .await;
+
+    assert_eq!(links.len(), 2);
+    assert_not_found_err(links.pop().unwrap());
+    let (recording, access_link) = links.pop().unwrap().unwrap();
+    assert_eq!(*recording.id.as_uuid(), ids[0]);
+    assert_eq!(
+        access_link.download_link,
+        "https://bucket.s3.amazonaws.com/0.mp4"
+    );
+    server.received();
+    let link_request = server.received();
+    assert!(link_request.contains(&format!(
+        "/recordings/{}/access-link?valid_for_secs=60",
+        ids[0]
+    )));
+    assert!(server
+        .received()
+        .contains(&format!("/recordings/{}/access-link", ids[2])));
+}
@@ -0,0 +1,639 @@
+use std::time::Duration;
+
+use dailyco::configuration::{
+    AutoTranscriptionSettings, AwsRegion, DailyLang, RecordingType, RecordingsBucket, Region,
+    RtmpGeoRegion, SfuSwitchover, SignalingImp, SipMode, SipSettings, TranscriptionLanguage,
+    TranscriptionModel,
+};
+use dailyco::meeting_token::{Allowed, TokenPermissions};
+use dailyco::room::{CreateRoom, RoomPrivacy, UpdateRoom};
+use dailyco::{Error, OwnedRoomProperties, RoomProperties, RoomPropertiesBuilder};
+use serde_json::{json, Value};
+
+use crate::helpers::{json_response, room_json, MockServer};
+
+fn recordings_bucket() -> RecordingsBucket {
+    RecordingsBucket {
+        bucket_name: "bucket".to_string(),
+        bucket_region: AwsRegion::UsWest2,
+        assume_role_arn: "arn:aws:iam::123456789012:role/daily".to_string(),
+        allow_api_access: true,
+        allow_streaming_from_bucket: None,
+    }
+}
+
+fn sip_settings() -> SipSettings {
+    SipSettings {
+        display_name: Some("Front desk".to_string()),
+        video: Some(false),
+        sip_mode: Some(SipMode::DialIn),
+        num_endpoints: Some(2),
+    }
+}
+
+fn fully_populated<'a>(
+    bucket: &'a RecordingsBucket,
+    transcription: &'a AutoTranscriptionSettings,
+    sip: &'a SipSettings,
+) -> RoomPropertiesBuilder<'a> {
+    RoomPropertiesBuilder::new()
+        .nbf(1)
+        .exp(2)
+        .max_participants(3)
+        .enable_people_ui(true)
+        .enable_pip_ui(true)
+        .enable_prejoin_ui(true)
+        .enable_network_ui(true)
+        .enable_knocking(true)
+        .enable_screenshare(true)
+        .enable_video_processing_ui(true)
+        .enable_chat(true)
+        .enable_advanced_chat(true)
+        .start_video_off(true)
+        .start_audio_off(true)
+        .owner_only_broadcast(true)
+        .enable_recording(RecordingType::Cloud)
+        .eject_at_room_exp(true)
+        .eject_after_elapsed(4)
+        .enable_hidden_participants(true)
+        .enable_mesh_sfu(false)
+        .experimental_optimize_large_calls(true)
+        .lang(DailyLang::Fr)
+        .meeting_join_hook("https://example.com/hook")
+        .signaling_imp(SignalingImp::Ws)
+        .geo(Region::EuWest2)
+        .rtmp_geo(RtmpGeoRegion::UsWest2)
+        .enable_terse_logging(true)
+        .recordings_template("{room_name}")
+        .recordings_bucket(bucket)
+        .sfu_switchover(SfuSwitchover::AtCount(5))
+        .auto_start_transcription(true)
+        .auto_transcription_settings(transcription)
+        .enable_dialin(true)
+        .sip(sip)
+        .permissions(&LISTEN_ONLY)
+}
+
+static LISTEN_ONLY: TokenPermissions = TokenPermissions {
+    has_presence: Some(true),
+    can_send: Some(Allowed::All(false)),
+    can_admin: None,
+};
+
+#[test]
+fn extra_properties_are_sent_with_typed_ones() {
+    let properties = RoomPropertiesBuilder::new()
+        .start_audio_off(true)
+        .extra("enable_emoji_reactions", json!(true));
+
+    assert_eq!(
+        serde_json::to_value(properties).unwrap(),
+        json!({ "start_audio_off": true, "enable_emoji_reactions": true })
+    );
+}
+
+#[test]
+fn extra_properties_never_override_typed_ones() {
+    let bucket = recordings_bucket();
+    let sip = sip_settings();
+    let transcription = AutoTranscriptionSettings::default();
+    let serialized = serde_json::to_value(fully_populated(&bucket, &transcription, &sip)).unwrap();
+    let Value::Object(typed) = serialized else {
+        panic!("Room properties should serialize to an object");
+    };
+
+    for key in typed.keys() {
+        let properties = RoomPropertiesBuilder::new().extra(key, json!("overridden"));
+        assert_eq!(
+            serde_json::to_value(properties).unwrap(),
+            json!({}),
+            "`{key}` should not be settable through `extra`"
+        );
+    }
+}
+
+#[test]
+fn default_permissions_are_sent_and_read_back() {
+    let serialized =
+        serde_json::to_value(RoomPropertiesBuilder::new().permissions(&LISTEN_ONLY)).unwrap();
+    assert_eq!(
+        serialized,
+        json!({ "permissions": { "hasPresence": true, "canSend": false } })
+    );
+
+    let properties: RoomProperties = serde_json::from_value(serialized).unwrap();
+    assert_eq!(properties.permissions.as_ref(), Some(&LISTEN_ONLY));
+    assert!(properties.extra.is_empty());
+}
+
+#[test]
+fn bucket_streaming_is_sent_and_read_back() {
+    let bucket = RecordingsBucket {
+        allow_streaming_from_bucket: Some(true),
+        ..recordings_bucket()
+    };
+    let serialized =
+        serde_json::to_value(RoomPropertiesBuilder::new().recordings_bucket(&bucket)).unwrap();
+    assert_eq!(
+        serialized["recordings_bucket"]["allow_streaming_from_bucket"],
+        json!(true)
+    );
+
+    let properties: RoomProperties = serde_json::from_value(serialized).unwrap();
+    assert_eq!(properties.recordings_bucket, Some(bucket));
+}
+
+#[test]
+fn room_properties_round_trip_through_json() {
+    let bucket = recordings_bucket();
+    let sip = sip_settings();
+    let transcription = AutoTranscriptionSettings::default();
+    let properties =
+        fully_populated(&bucket, &transcription, &sip).extra("enable_emoji_reactions", json!(true));
+
+    let template = RoomPropertiesBuilder::from_json(&properties.to_json()).unwrap();
+    assert_eq!(template.as_builder(), properties);
+
+    let res = RoomPropertiesBuilder::from_json(r#"{"max_participants": "many"}"#);
+    assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+}
+
+#[tokio::test]
+async fn owned_properties_are_reused_across_rooms() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-id", "a")),
+        json_response("200 OK", &room_json("b-id", "b")),
+    ]);
+    let client = server.client();
+    let bucket = recordings_bucket();
+    let sip = sip_settings();
+    let transcription = AutoTranscriptionSettings::default();
+    let properties =
+        fully_populated(&bucket, &transcription, &sip).extra("enable_emoji_reactions", json!(true));
+    let owned = OwnedRoomProperties::from(properties.clone());
+    assert_eq!(owned.as_builder().to_json(), properties.to_json());
+    // The owned properties outlive what the builder borrowed
+    drop(bucket);
+
+    for name in ["a", "b"] {
+        CreateRoom::new()
+            .name(name)
+            .properties_owned(&owned)
+            .send(&client)
+            .await
+            .unwrap();
+        let request = server.received();
+        let body = request.split("\r\n\r\n").nth(1).unwrap();
+        let body: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["properties"]["max_participants"], json!(3));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "strict-deserialize"))]
+fn unknown_room_properties_are_preserved() {
+    let properties: RoomProperties = serde_json::from_value(json!({
+        "start_audio_off": true,
+        "enable_emoji_reactions": true,
+    }))
+    .unwrap();
+
+    assert_eq!(properties.start_audio_off, Some(true));
+    assert_eq!(properties.extra.len(), 1);
+    assert_eq!(properties.extra["enable_emoji_reactions"], json!(true));
+}
+
+#[test]
+#[cfg(feature = "strict-deserialize")]
+fn unknown_room_properties_are_rejected() {
+    let err = serde_json::from_value::<RoomProperties>(json!({
+        "start_audio_off": true,
+        "enable_emoji_reactions": true,
+    }))
+    .unwrap_err();
+
+    assert!(err.to_string().contains("enable_emoji_reactions"));
+}
+
+#[test]
+fn merged_properties_override_only_what_is_set() {
+    let bucket = recordings_bucket();
+    let sip = sip_settings();
+    let transcription = AutoTranscriptionSettings::default();
+    let base = fully_populated(&bucket, &transcription, &sip)
+        .extra("enable_emoji_reactions", json!(true))
+        .extra("enable_hand_raising", json!(true));
+
+    assert_eq!(base.clone().merge(RoomPropertiesBuilder::new()), base);
+    assert_eq!(
+        RoomPropertiesBuilder::new().merge(base.clone()),
+        base,
+        "Every property set on the override should be kept"
+    );
+
+    let overrides = RoomPropertiesBuilder::new()
+        .max_participants(4)
+        .lang(DailyLang::Fr)
+        .extra("enable_hand_raising", json!(false));
+    let merged = serde_json::to_value(base.clone().merge(overrides)).unwrap();
+    let mut expected = serde_json::to_value(&base).unwrap();
+    expected["max_participants"] = json!(4);
+    expected["lang"] = json!("fr");
+    expected["enable_hand_raising"] = json!(false);
+    assert_eq!(merged, expected);
+}
+
+#[test]
+fn diff_reports_only_set_properties_which_differ() {
+    let live: RoomProperties = serde_json::from_value(json!({
+        "max_participants": 10,
+        "start_video_off": true,
+        "sfu_switchover": 2,
+        "enable_chat": true,
+    }))
+    .unwrap();
+    let template = RoomPropertiesBuilder::new()
+        .max_participants(8)
+        .start_video_off(true)
+        .sfu_switchover(SfuSwitchover::AtCount(2))
+        .enable_knocking(true)
+        .enable_people_ui(false);
+
+    let diffs = live.diff(&template);
+    let diffs: Vec<_> = diffs
+        .iter()
+        .map(|diff| (diff.field.as_str(), &diff.current, &diff.desired))
+        .collect();
+    assert_eq!(
+        diffs,
+        [
+            ("enable_knocking", &Value::Null, &json!(true)),
+            ("enable_people_ui", &Value::Null, &json!(false)),
+            ("max_participants", &json!(10), &json!(8)),
+        ]
+    );
+    assert!(live.diff(&RoomPropertiesBuilder::new()).is_empty());
+}
+
+#[test]
+fn relative_timestamps_are_offset_from_now() {
+    let now = chrono::Utc::now().timestamp();
+    let properties = RoomPropertiesBuilder::new()
+        .not_before_in(Duration::from_secs(60))
+        .expires_in(Duration::from_secs(3600));
+
+    let serialized = serde_json::to_value(properties).unwrap();
+    let nbf = serialized["nbf"].as_i64().unwrap();
+    let exp = serialized["exp"].as_i64().unwrap();
+    assert!((now + 60..=now + 61).contains(&nbf));
+    assert!((now + 3600..=now + 3601).contains(&exp));
+}
+
+#[test]
+fn relative_timestamps_saturate_instead_of_overflowing() {
+    let properties = RoomPropertiesBuilder::new().expires_in(Duration::MAX);
+    assert_eq!(serde_json::to_value(properties).unwrap()["exp"], i64::MAX);
+
+    let properties = RoomPropertiesBuilder::new().not_before_in(Duration::from_secs(u64::MAX));
+    assert_eq!(serde_json::to_value(properties).unwrap()["nbf"], i64::MAX);
+}
+
+#[test]
+fn valid_window_sets_nbf_and_exp_together() {
+    let now = chrono::Utc::now().timestamp();
+    let properties = RoomPropertiesBuilder::new()
+        .valid_window(Duration::from_secs(15 * 60), Duration::from_secs(3600));
+    assert!(properties.validate().is_ok());
+
+    let serialized = serde_json::to_value(properties).unwrap();
+    let nbf = serialized["nbf"].as_i64().unwrap();
+    let exp = serialized["exp"].as_i64().unwrap();
+    assert!((now + 15 * 60..=now + 15 * 60 + 1).contains(&nbf));
+    assert_eq!(exp - nbf, 3600);
+
+    let empty_window = RoomPropertiesBuilder::new().valid_window(Duration::ZERO, Duration::ZERO);
+    assert!(matches!(
+        empty_window.validate(),
+        Err(Error::InvalidConfig { .. })
+    ));
+}
+
+#[test]
+fn eject_after_is_sent_in_seconds() {
+    let properties =
+        RoomPropertiesBuilder::new().eject_after(Duration::from_millis(45 * 60 * 1000 + 1));
+    let serialized = serde_json::to_value(properties).unwrap();
+    assert_eq!(serialized["eject_after_elapsed"], 45 * 60 + 1);
+}
+
+#[test]
+fn domain_inherited_settings_are_absent_when_unset() {
+    let properties: RoomProperties = serde_json::from_value(json!({})).unwrap();
+    assert_eq!(properties.enable_pip_ui, None);
+    assert_eq!(properties.enable_video_processing_ui, None);
+    assert_eq!(properties.enable_advanced_chat, None);
+    // Nor are settings with a documented default filled in with it
+    assert_eq!(properties.enable_screenshare, None);
+    assert_eq!(properties.enable_chat, None);
+    assert_eq!(properties.lang, None);
+
+    let properties: RoomProperties = serde_json::from_value(json!({
+        "enable_pip_ui": true,
+        "enable_video_processing_ui": false,
+        "enable_advanced_chat": true,
+    }))
+    .unwrap();
+    assert_eq!(properties.enable_pip_ui, Some(true));
+    assert_eq!(properties.enable_video_processing_ui, Some(false));
+    assert_eq!(properties.enable_advanced_chat, Some(true));
+}
+
+#[test]
+fn ui_toggles_distinguish_false_from_absent() {
+    let parse = |config: Value| serde_json::from_value::<RoomProperties>(config).unwrap();
+
+    let properties = parse(json!({ "enable_network_ui": true, "enable_people_ui": true }));
+    assert_eq!(properties.enable_network_ui, Some(true));
+    assert_eq!(properties.enable_people_ui, Some(true));
+
+    let properties = parse(json!({ "enable_network_ui": false, "enable_people_ui": false }));
+    assert_eq!(properties.enable_network_ui, Some(false));
+    assert_eq!(properties.enable_people_ui, Some(false));
+
+    let properties = parse(json!({}));
+    assert_eq!(properties.enable_network_ui, None);
+    assert_eq!(properties.enable_people_ui, None);
+}
+
+#[test]
+fn auto_transcription_settings_roundtrip() {
+    let settings = AutoTranscriptionSettings {
+        language: Some(TranscriptionLanguage::EnGb),
+        model: Some(TranscriptionModel::Nova2Meeting),
+        profanity_filter: Some(true),
+        ..Default::default()
+    };
+    let properties = RoomPropertiesBuilder::new()
+        .auto_start_transcription(true)
+        .auto_transcription_settings(&settings);
+
+    let serialized = serde_json::to_value(properties).unwrap();
+    assert_eq!(
+        serialized,
+        json!({
+            "auto_start_transcription": true,
+            "auto_transcription_settings": {
+                "language": "en-GB",
+                "model": "nova-2-meeting",
+                "profanity_filter": true,
+            },
+        })
+    );
+
+    let properties: RoomProperties = serde_json::from_value(serialized).unwrap();
+    assert_eq!(properties.auto_start_transcription, Some(true));
+    assert_eq!(properties.auto_transcription_settings, Some(settings));
+    assert!(properties.extra.is_empty());
+}
+
+#[test]
+fn sip_settings_are_sent_and_read_back() {
+    let sip = sip_settings();
+    let serialized =
+        serde_json::to_value(RoomPropertiesBuilder::new().enable_dialin(true).sip(&sip)).unwrap();
+    assert_eq!(
+        serialized,
+        json!({
+            "enable_dialin": true,
+            "sip": {
+                "display_name": "Front desk",
+                "video": false,
+                "sip_mode": "dial-in",
+                "num_endpoints": 2,
+            },
+        })
+    );
+
+    let properties: RoomProperties = serde_json::from_value(serialized).unwrap();
+    assert_eq!(properties.enable_dialin, Some(true));
+    assert_eq!(properties.sip, Some(sip));
+    assert!(properties.extra.is_empty());
+}
+
+#[test]
+fn unknown_sip_modes_are_tolerated() {
+    let properties: RoomProperties =
+        serde_json::from_value(json!({ "sip": { "sip_mode": "dial-out" } })).unwrap();
+    assert_eq!(properties.sip.unwrap().sip_mode, Some(SipMode::Unknown));
+}
+
+#[tokio::test]
+async fn contradictory_room_properties_are_rejected_locally() {
+    let server = MockServer::start(vec![]);
+    let client = server.client();
+    let bucket = recordings_bucket();
+    let cases = [
+        RoomPropertiesBuilder::new().nbf(20).exp(10),
+        RoomPropertiesBuilder::new().max_participants(0),
+        RoomPropertiesBuilder::new().eject_after_elapsed(-5),
+        RoomPropertiesBuilder::new()
+            .enable_mesh_sfu(true)
+            .sfu_always(),
+    ];
+    for properties in cases {
+        assert!(matches!(
+            properties.validate(),
+            Err(Error::InvalidConfig { .. })
+        ));
+        let res = CreateRoom::new()
+            .properties(properties.clone())
+            .send(&client)
+            .await;
+        assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+        let res = UpdateRoom::new()
+            .properties(properties)
+            .send("a-room", &client)
+            .await;
+        assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+    }
+
+    let sip = sip_settings();
+    let transcription = AutoTranscriptionSettings::default();
+    assert!(fully_populated(&bucket, &transcription, &sip)
+        .validate_strict()
+        .is_ok());
+}
+
+#[tokio::test]
+async fn incompatible_recording_settings_are_rejected_when_strict() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-id", "a")),
+        json_response("200 OK", &room_json("b-id", "b")),
+    ]);
+    let client = server.client();
+    let bucket = recordings_bucket();
+    let cases = [
+        RoomPropertiesBuilder::new()
+            .enable_recording(RecordingType::Local)
+            .owner_only_broadcast(true),
+        RoomPropertiesBuilder::new()
+            .enable_recording(RecordingType::Local)
+            .recordings_bucket(&bucket),
+    ];
+    for properties in cases {
+        assert!(properties.validate().is_ok());
+        assert!(matches!(
+            properties.validate_strict(),
+            Err(Error::InvalidConfig { .. })
+        ));
+        let res = CreateRoom::new()
+            .properties(properties.clone())
+            .strict(true)
+            .send(&client)
+            .await;
+        assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+        // Left for `Daily` to decide otherwise
+        CreateRoom::new()
+            .properties(properties)
+            .send(&client)
+            .await
+            .unwrap();
+    }
+}
+
+#[test]
+fn terse_logging_is_enabled_for_large_rooms() {
+    let terse = |builder: RoomPropertiesBuilder| {
+        serde_json::to_value(builder.auto_terse_logging()).unwrap()["enable_terse_logging"].clone()
+    };
+    let threshold = RoomPropertiesBuilder::TERSE_LOGGING_PARTICIPANTS;
+
+    assert_eq!(
+        terse(RoomPropertiesBuilder::new().max_participants(threshold + 1)),
+        true
+    );
+    assert_eq!(
+        terse(RoomPropertiesBuilder::new().max_participants(threshold)),
+        Value::Null
+    );
+    assert_eq!(terse(RoomPropertiesBuilder::new()), Value::Null);
+    assert_eq!(
+        terse(
+            RoomPropertiesBuilder::new()
+                .max_participants(threshold + 1)
+                .enable_terse_logging(false)
+        ),
+        false
+    );
+}
+
+#[test]
+fn sfu_switchover_is_sent_as_daily_expects() {
+    let sent = |switchover: SfuSwitchover| {
+        serde_json::to_value(RoomPropertiesBuilder::new().sfu_switchover(switchover)).unwrap()
+            ["sfu_switchover"]
+            .as_f64()
+            .unwrap()
+    };
+    assert_eq!(sent(SfuSwitchover::Always), 0.5);
+    assert_eq!(sent(SfuSwitchover::AtCount(4)), 4.);
+    assert_eq!(sent(SfuSwitchover::Never), f64::from(u32::MAX));
+
+    #[allow(deprecated)]
+    for raw in [-1., f64::NAN] {
+        let properties = RoomPropertiesBuilder::new().sfu_switchover_f64(raw);
+        assert!(matches!(
+            properties.validate(),
+            Err(Error::InvalidConfig { .. })
+        ));
+    }
+    #[allow(deprecated)]
+    let raw = RoomPropertiesBuilder::new().sfu_switchover_f64(2.5);
+    assert!(raw.validate().is_ok());
+}
+
+// Locks the wire format, so that a serde attribute changing (e.g. a rename or a
+// `skip_serializing_if`) doesn't silently change what is sent to `Daily`.
+#[tokio::test]
+async fn create_room_wire_format() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        &room_json("a-room-id", "a-room"),
+    )]);
+    let bucket = recordings_bucket();
+    let sip = sip_settings();
+    let transcription = AutoTranscriptionSettings {
+        language: Some(TranscriptionLanguage::EnGb),
+        model: Some(TranscriptionModel::Nova2Meeting),
+        profanity_filter: Some(true),
+        ..AutoTranscriptionSettings::default()
+    };
+
+    CreateRoom::new()
+        .name("a-room")
+        .privacy(RoomPrivacy::Private)
+        .properties(fully_populated(&bucket, &transcription, &sip))
+        .send(&server.client())
+        .await
+        .unwrap();
+    let request = server.received();
+    let body: Value = serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "name": "a-room",
+            "privacy": "private",
+            "properties": {
+                "nbf": 1,
+                "exp": 2,
+                "max_participants": 3,
+                "enable_people_ui": true,
+                "enable_pip_ui": true,
+                "enable_prejoin_ui": true,
+                "enable_network_ui": true,
+                "enable_knocking": true,
+                "enable_screenshare": true,
+                "enable_video_processing_ui": true,
+                "enable_chat": true,
+                "enable_advanced_chat": true,
+                "start_video_off": true,
+                "start_audio_off": true,
+                "owner_only_broadcast": true,
+                "enable_recording": "cloud",
+                "eject_at_room_exp": true,
+                "eject_after_elapsed": 4,
+                "enable_hidden_participants": true,
+                "enable_mesh_sfu": false,
+                "experimental_optimize_large_calls": true,
+                "lang": "fr",
+                "meeting_join_hook": "https://example.com/hook",
+                "signaling_imp": "ws",
+                "geo": "eu-west-2",
+                "rtmp_geo": "us-west-2",
+                "enable_terse_logging": true,
+                "recordings_template": "{room_name}",
+                "recordings_bucket": {
+                    "bucket_name": "bucket",
+                    "bucket_region": "us-west-2",
+                    "assume_role_arn": "arn:aws:iam::123456789012:role/daily",
+                    "allow_api_access": true,
+                },
+                "sfu_switchover": 5.0,
+                "auto_start_transcription": true,
+                "auto_transcription_settings": {
+                    "language": "en-GB",
+                    "model": "nova-2-meeting",
+                    "profanity_filter": true,
+                },
+                "permissions": { "hasPresence": true, "canSend": false },
+                "enable_dialin": true,
+                "sip": {
+                    "display_name": "Front desk",
+                    "video": false,
+                    "sip_mode": "dial-in",
+                    "num_endpoints": 2,
+                },
+            },
+        })
+    );
+}
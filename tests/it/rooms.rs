@@ -1,9 +1,17 @@
+use std::time::Duration;
+
+use dailyco::configuration::SfuSwitchover;
 use dailyco::meeting_token::CreateMeetingToken;
-use dailyco::room::{CreateRoom, RoomPrivacy, UpdateRoom};
-use dailyco::RoomPropertiesBuilder;
+use dailyco::room::{Conflict, CreateRoom, GetRooms, JoinHookEvent, Room, RoomPrivacy, UpdateRoom};
+use dailyco::{DailyCoErrorInfo, DailyCoErrorKind, Error, RoomPropertiesBuilder};
+use futures_util::TryStreamExt;
 use nanoid::nanoid;
+use serde_json::json;
 
-use crate::helpers::{assert_not_found_err, cleanup_room, create_default_room, get_daily_client};
+use crate::helpers::{
+    assert_not_found_err, cleanup_room, create_default_room, get_daily_client, json_response,
+    presence_json, room_json, MockServer,
+};
 
 #[tokio::test]
 async fn can_make_room() {
@@ -24,8 +32,8 @@ async fn can_make_room() {
 
     assert_eq!(room.name, room_name);
     assert_eq!(room.privacy, RoomPrivacy::Private);
-    assert!(room.config.start_audio_off);
-    assert!(room.config.start_video_off);
+    assert_eq!(room.config.start_audio_off, Some(true));
+    assert_eq!(room.config.start_video_off, Some(true));
 
     cleanup_room(&client, &room_name).await;
 }
@@ -37,6 +45,41 @@ async fn get_room_not_found() {
     assert_not_found_err(res);
 }
 
+#[tokio::test]
+async fn room_exists() {
+    let client = get_daily_client();
+    let room = create_default_room(&client).await;
+    assert!(client.room_exists(&room.name).await.unwrap());
+
+    cleanup_room(&client, &room.name).await;
+    assert!(!client.room_exists(&room.name).await.unwrap());
+}
+
+#[tokio::test]
+async fn try_get_room() {
+    let server = MockServer::start(vec![
+        json_response(
+            "404 Not Found",
+            r#"{"error":"not-found","info":"room missing not found"}"#,
+        ),
+        json_response(
+            "401 Unauthorized",
+            r#"{"error":"authentication-error","info":"bad key"}"#,
+        ),
+    ]);
+    let client = server.client();
+
+    assert!(client.try_get_room("missing").await.unwrap().is_none());
+    let err = client.try_get_room("a-room").await.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::APIError(DailyCoErrorInfo {
+            error: Some(DailyCoErrorKind::AuthenticationError),
+            ..
+        })
+    ));
+}
+
 #[tokio::test]
 async fn delete_room() {
     let client = get_daily_client();
@@ -49,6 +92,422 @@ async fn delete_room() {
     assert!(client.delete_room(&room_name).await.is_ok());
 }
 
+#[tokio::test]
+async fn delete_room_if_exists() {
+    let client = get_daily_client();
+    let room = create_default_room(&client).await;
+    assert!(client.delete_room_if_exists(&room.name).await.unwrap());
+    assert!(!client.delete_room_if_exists(&room.name).await.unwrap());
+}
+
+#[tokio::test]
+async fn delete_rooms() {
+    let not_found = json_response(
+        "404 Not Found",
+        r#"{"error":"not-found","info":"room b not found"}"#,
+    );
+    let deleted = || json_response("200 OK", r#"{"deleted":true}"#);
+    let server = MockServer::start(vec![deleted(), not_found.clone(), deleted(), not_found]);
+    let client = server.client();
+
+    let results = client.delete_rooms(&["a", "b"], 1, true).await;
+    assert!(results.iter().all(Result::is_ok));
+
+    let mut results = client.delete_rooms(&["a", "b"], 1, false).await;
+    assert_not_found_err(results.pop().unwrap());
+    assert!(results.pop().unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn get_rooms_by_name() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-id", "a")),
+        json_response(
+            "404 Not Found",
+            r#"{"error":"not-found","info":"room b not found"}"#,
+        ),
+        json_response("200 OK", &room_json("c-id", "c")),
+    ]);
+    let client = server.client();
+
+    let mut results = client.get_rooms_by_name(&["a", "b", "c"], 1).await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.pop().unwrap().unwrap().id, "c-id");
+    assert_not_found_err(results.pop().unwrap());
+    assert_eq!(results.pop().unwrap().unwrap().id, "a-id");
+
+    assert!(client.get_rooms_by_name(&[], 4).await.is_empty());
+}
+
+#[tokio::test]
+async fn idempotent_create_returns_the_existing_room() {
+    let already_exists = || {
+        json_response(
+            "400 Bad Request",
+            r#"{"error":"invalid-request-error","info":"a room named a-room already exists"}"#,
+        )
+    };
+    let server = MockServer::start(vec![
+        already_exists(),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        already_exists(),
+    ]);
+    let client = server.client();
+
+    let room = CreateRoom::new()
+        .name("a-room")
+        .idempotent(true)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(room.id, "a-room-id");
+    assert!(server.received().starts_with("POST /rooms/ "));
+    assert!(server.received().starts_with("GET /rooms/a-room "));
+
+    let res = CreateRoom::new().name("a-room").send(&client).await;
+    assert!(matches!(res, Err(Error::APIError(_))));
+}
+
+#[tokio::test]
+async fn room_remaining_capacity_counts_present_participants() {
+    let capped_room = |max: usize| {
+        room_json("a-room-id", "a-room").replace(
+            r#""config":{}"#,
+            &format!(r#""config":{{"max_participants":{max}}}"#),
+        )
+    };
+    let presence = |count: usize| presence_json("a-room", count);
+    let server = MockServer::start(vec![
+        json_response("200 OK", &capped_room(10)),
+        json_response("200 OK", &presence(3)),
+        json_response("200 OK", &capped_room(2)),
+        json_response("200 OK", &presence(5)),
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+    ]);
+    let client = server.client();
+
+    assert_eq!(
+        client.room_remaining_capacity("a-room").await.unwrap(),
+        Some(7)
+    );
+    assert!(server.received().starts_with("GET /rooms/a-room "));
+    assert!(server.received().starts_with("GET /rooms/a-room/presence "));
+    assert_eq!(
+        client.room_remaining_capacity("a-room").await.unwrap(),
+        Some(0)
+    );
+    server.received();
+    server.received();
+    // Without a maximum the presence isn't fetched at all
+    assert_eq!(
+        client.room_remaining_capacity("a-room").await.unwrap(),
+        None
+    );
+    assert!(server.received().starts_with("GET /rooms/a-room "));
+}
+
+#[tokio::test]
+async fn get_room_presence() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &presence_json("a-room", 2)),
+        json_response("200 OK", &presence_json("a-room", 0)),
+    ]);
+    let client = server.client();
+
+    let participants = client.get_room_presence("a-room").await.unwrap();
+    assert!(server.received().starts_with("GET /rooms/a-room/presence "));
+    let names: Vec<_> = participants
+        .iter()
+        .map(|participant| participant.user_name.as_deref().unwrap())
+        .collect();
+    assert_eq!(names, ["user-0", "user-1"]);
+    assert!(participants
+        .iter()
+        .all(|participant| participant.room == "a-room" && participant.user_id.is_none()));
+
+    assert!(client.get_room_presence("a-room").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn name_conflicts_are_handled_as_requested() {
+    let already_exists = || {
+        json_response(
+            "400 Bad Request",
+            r#"{"error":"invalid-request-error","info":"a room named standup already exists"}"#,
+        )
+    };
+    let server = MockServer::start(vec![
+        already_exists(),
+        json_response("200 OK", &room_json("standup-id", "standup")),
+        already_exists(),
+        already_exists(),
+        json_response("200 OK", &room_json("suffixed-id", "standup-suffixed")),
+        already_exists(),
+        already_exists(),
+        json_response("200 OK", &room_json("suffixed-id", "standup-suffixed")),
+        already_exists(),
+    ]);
+    let client = server.client();
+    let sent_name = |request: String| {
+        let body = request.split("\r\n\r\n").nth(1).unwrap().to_string();
+        serde_json::from_str::<serde_json::Value>(&body).unwrap()["name"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    };
+
+    let room = CreateRoom::new()
+        .name("standup")
+        .on_conflict(Conflict::ReturnExisting)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(room.id, "standup-id");
+    server.received();
+    assert!(server.received().starts_with("GET /rooms/standup "));
+
+    // The first suffix is taken too, so a second one is tried
+    let mut suffixed = Vec::new();
+    for _ in 0..2 {
+        let room = CreateRoom::new()
+            .name("standup")
+            .on_conflict(Conflict::Suffix)
+            .suffix_seed(7)
+            .send(&client)
+            .await
+            .unwrap();
+        assert_eq!(room.id, "suffixed-id");
+        assert_eq!(sent_name(server.received()), "standup");
+        let names = [sent_name(server.received()), sent_name(server.received())];
+        assert_ne!(names[0], names[1]);
+        assert!(names.iter().all(|name| name.len() == "standup-".len() + 6));
+        suffixed.push(names);
+    }
+    assert_eq!(suffixed[0], suffixed[1]);
+
+    let res = CreateRoom::new().name("standup").send(&client).await;
+    assert!(matches!(res, Err(Error::APIError(_))));
+}
+
+#[test]
+fn rooms_round_trip_through_serde() {
+    let mut room: Room = serde_json::from_str(&room_json("a-room-id", "a-room")).unwrap();
+    let config = json!({
+        "exp": 1700000000,
+        "enable_chat": true,
+        "enable_screenshare": false,
+        "lang": "pt-BR",
+        "geo": "eu-west-2",
+        "sfu_switchover": 2.5,
+    });
+    room.config = serde_json::from_value(config.clone()).unwrap();
+
+    let serialized = serde_json::to_value(&room).unwrap();
+    // Properties `Daily` didn't return aren't added back, e.g. as `null`
+    assert_eq!(serialized["config"], config);
+    let round_tripped: Room = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&round_tripped).unwrap(), serialized);
+    assert_eq!(round_tripped.name, "a-room");
+    assert_eq!(round_tripped.config.exp, Some(1700000000));
+    assert_eq!(round_tripped.config.enable_chat, Some(true));
+    assert_eq!(round_tripped.config.enable_screenshare, Some(false));
+    assert_eq!(round_tripped.config.sfu_switchover, Some(2.5));
+}
+
+#[test]
+fn join_url_keeps_existing_query() {
+    let mut room: Room = serde_json::from_str(&room_json("a-room-id", "a-room")).unwrap();
+    assert_eq!(
+        room.join_url_with_token("a.b.c"),
+        "https://your-domain.daily.co/a-room?t=a.b.c"
+    );
+
+    room.url = "https://your-domain.daily.co/a-room?lang=fr".to_string();
+    assert_eq!(
+        room.join_url_with_token("a.b.c"),
+        "https://your-domain.daily.co/a-room?lang=fr&t=a.b.c"
+    );
+}
+
+#[tokio::test]
+async fn create_room_with_token_links_to_the_room() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", r#"{"token":"header.payload.signature"}"#),
+    ]);
+
+    let link = server
+        .client()
+        .create_room_with_token(
+            CreateRoom::new().name("a-room"),
+            &CreateMeetingToken::new().is_owner(true),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(link.room.name, "a-room");
+    assert_eq!(link.token.as_str(), "header.payload.signature");
+    assert_eq!(
+        link.url,
+        "https://your-domain.daily.co/a-room?t=header.payload.signature"
+    );
+    server.received();
+    let token_request = server.received();
+    assert!(token_request.contains(r#""room_name":"a-room""#));
+    assert!(token_request.contains(r#""is_owner":true"#));
+}
+
+#[tokio::test]
+async fn sessions_combine_presence_and_past_meetings() {
+    let active_id = "11111111-1111-4111-8111-111111111111";
+    let past_id = "22222222-2222-4222-8222-222222222222";
+    let server = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":1,"data":[{{"room":"a-room","id":"{active_id}","userId":"user-1","userName":"Ada","joinTime":"2023-01-01T20:53:19.000Z","duration":30}}]}}"#
+            ),
+        ),
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":2,"data":[
+                    {{"id":"m-2","room":"a-room","start_time":1700000000,"duration":60,"ongoing":true,"max_participants":1,"participants":[
+                        {{"user_id":"user-1","participant_id":"{active_id}","user_name":"Ada","join_time":1700000000,"duration":20}}
+                    ]}},
+                    {{"id":"m-1","room":"a-room","start_time":1600000000,"duration":90,"ongoing":false,"max_participants":1,"participants":[
+                        {{"user_id":null,"participant_id":"{past_id}","user_name":"Grace","join_time":1600000010,"duration":80}}
+                    ]}}
+                ]}}"#
+            ),
+        ),
+    ]);
+    let client = server.client();
+
+    let now = chrono::Utc::now().timestamp();
+    let sessions = client.list_sessions("a-room").await.unwrap();
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].session_id.to_string(), active_id);
+    assert!(sessions[0].active);
+    assert_eq!(sessions[0].duration, 30);
+    assert!((now - 31..=now - 29).contains(&sessions[0].join_time));
+    assert_eq!(sessions[1].session_id.to_string(), past_id);
+    assert!(!sessions[1].active);
+    assert_eq!(sessions[1].user_name.as_deref(), Some("Grace"));
+    assert_eq!(sessions[1].join_time, 1600000010);
+
+    assert!(server.received().starts_with("GET /rooms/a-room/presence "));
+    assert!(server.received().starts_with("GET /meetings?room=a-room "));
+}
+
+#[tokio::test]
+async fn room_privacy_is_updated_alone() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        &room_json("a-room-id", "a-room"),
+    )]);
+
+    let room = server
+        .client()
+        .set_room_privacy("a-room", RoomPrivacy::Private)
+        .await
+        .unwrap();
+    assert_eq!(room.name, "a-room");
+    let request = server.received();
+    assert!(request.starts_with("POST /rooms/a-room "));
+    assert!(request.ends_with(r#"{"privacy":"private"}"#));
+}
+
+#[tokio::test]
+async fn rooms_are_only_refetched_when_changed() {
+    let room = room_json("a-room-id", "a-room");
+    let with_etag = |etag: &str| {
+        format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\netag: {etag}\r\ncontent-length: {}\r\n\r\n{room}",
+            room.len()
+        )
+    };
+    let server = MockServer::start(vec![
+        with_etag("\"v1\""),
+        "HTTP/1.1 304 Not Modified\r\netag: \"v1\"\r\n\r\n".to_string(),
+        with_etag("\"v2\""),
+        json_response("200 OK", &room),
+    ]);
+    let client = server.client();
+
+    let (room, etag) = client
+        .get_room_if_changed("a-room", None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(room.name, "a-room");
+    assert_eq!(etag.as_deref(), Some("\"v1\""));
+    assert!(!server.received().contains("if-none-match"));
+
+    let unchanged = client
+        .get_room_if_changed("a-room", etag.as_deref())
+        .await
+        .unwrap();
+    assert!(unchanged.is_none());
+    assert!(server.received().contains("if-none-match: \"v1\"\r\n"));
+
+    let (_, etag) = client
+        .get_room_if_changed("a-room", Some("\"v1\""))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(etag.as_deref(), Some("\"v2\""));
+    server.received();
+
+    // Without an `ETag` from `Daily`, the room is always refetched
+    let (_, etag) = client
+        .get_room_if_changed("a-room", None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(etag, None);
+}
+
+#[tokio::test]
+async fn ephemeral_rooms_expire_with_their_owner_token() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", &room_json("a-room-id", "a-room")),
+        json_response("200 OK", r#"{"token":"header.payload.signature"}"#),
+    ]);
+    let client = server.client();
+    let body = |request: String| -> serde_json::Value {
+        serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap()
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let link = client
+        .create_ephemeral_room(
+            Duration::from_secs(600),
+            RoomPropertiesBuilder::new()
+                .max_participants(2)
+                .eject_at_room_exp(false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(link.token.as_str(), "header.payload.signature");
+
+    let room = body(server.received());
+    let token = body(server.received());
+    let exp = room["properties"]["exp"].as_i64().unwrap();
+    assert!((now + 600..=now + 601).contains(&exp));
+    assert_eq!(room["privacy"], "private");
+    assert_eq!(room["properties"]["eject_at_room_exp"], true);
+    assert_eq!(room["properties"]["max_participants"], 2);
+    assert_eq!(token["properties"]["exp"], exp);
+    assert_eq!(token["properties"]["is_owner"], true);
+    assert_eq!(token["properties"]["room_name"], "a-room");
+
+    let res = client
+        .create_ephemeral_room(Duration::from_millis(500), RoomPropertiesBuilder::new())
+        .await;
+    assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+}
+
 #[tokio::test]
 async fn get_room() {
     let client = get_daily_client();
@@ -59,7 +518,7 @@ async fn get_room() {
             RoomPropertiesBuilder::new()
                 .eject_at_room_exp(true)
                 .max_participants(12)
-                .sfu_switchover(1.),
+                .sfu_switchover(SfuSwitchover::AtCount(1)),
         )
         .send(&client)
         .await
@@ -69,7 +528,7 @@ async fn get_room() {
     assert_eq!(room.privacy, RoomPrivacy::Public);
     assert!(room.api_created);
     assert_eq!(room.config.max_participants, Some(12));
-    assert!(room.config.eject_at_room_exp);
+    assert_eq!(room.config.eject_at_room_exp, Some(true));
     assert_eq!(room.config.sfu_switchover, Some(1.));
 
     cleanup_room(&client, &room_name).await;
@@ -81,16 +540,16 @@ async fn update_room() {
     let room = create_default_room(&client).await;
     let room_name = &room.name;
     UpdateRoom::new()
-        .properties(RoomPropertiesBuilder::new().sfu_switchover(2.))
+        .properties(RoomPropertiesBuilder::new().sfu_switchover(SfuSwitchover::AtCount(2)))
         .send(room_name, &client)
         .await
         .unwrap();
-    let room_after_update = client.get_room(&room_name).await.unwrap();
+    let room_after_update = client.get_room(room_name).await.unwrap();
     assert_eq!(&room_after_update.name, room_name);
     assert_eq!(room_after_update.privacy, RoomPrivacy::Public);
     assert_eq!(room_after_update.config.sfu_switchover, Some(2.));
 
-    cleanup_room(&client, &room_name).await;
+    cleanup_room(&client, room_name).await;
 }
 
 #[tokio::test]
@@ -106,6 +565,17 @@ async fn get_rooms() {
     cleanup_room(&client, &created_room.name).await;
 }
 
+#[tokio::test]
+async fn count_rooms() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        r#"{"total_count":42,"data":[]}"#,
+    )]);
+
+    assert_eq!(server.client().count_rooms().await.unwrap(), 42);
+    assert!(server.received().starts_with("GET /rooms/?limit=1 "));
+}
+
 #[tokio::test]
 async fn make_meeting_token() {
     let client = get_daily_client();
@@ -118,3 +588,254 @@ async fn make_meeting_token() {
         .unwrap();
     cleanup_room(&client, &room.name).await;
 }
+
+#[test]
+fn expire_after_sets_expiry_and_ejection() {
+    let now = chrono::Utc::now().timestamp();
+    let mut create_room = CreateRoom::new();
+    create_room
+        .properties(RoomPropertiesBuilder::new().start_audio_off(true))
+        .expire_after(Duration::from_secs(600));
+
+    let serialized = serde_json::to_value(&create_room).unwrap();
+    let properties = &serialized["properties"];
+    assert_eq!(properties["start_audio_off"], true);
+    assert_eq!(properties["eject_at_room_exp"], true);
+    let exp = properties["exp"].as_i64().unwrap();
+    assert!((now + 600..=now + 601).contains(&exp));
+}
+
+#[test]
+fn parse_join_hook_event() {
+    let body = br#"{
+        "room": "a-room",
+        "user_id": "user-1",
+        "user_name": "Ada",
+        "timestamp": 1700000000,
+        "domain": "your-domain"
+    }"#;
+
+    let event = JoinHookEvent::parse(body).unwrap();
+    assert_eq!(event.room, "a-room");
+    assert_eq!(event.user_id.as_deref(), Some("user-1"));
+    assert_eq!(event.user_name.as_deref(), Some("Ada"));
+    assert_eq!(event.timestamp, Some(1700000000));
+    assert_eq!(event.extra["domain"], "your-domain");
+
+    let err = JoinHookEvent::parse(b"{\"user_name\":\"Ada\"}").unwrap_err();
+    assert!(matches!(err, Error::Deserialize { .. }));
+}
+
+#[tokio::test]
+async fn get_rooms_pages() {
+    let server = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":3,"data":[{},{}]}}"#,
+                room_json("id-1", "room-1"),
+                room_json("id-2", "room-2")
+            ),
+        ),
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":3,"data":[{}]}}"#,
+                room_json("id-3", "room-3")
+            ),
+        ),
+    ]);
+    let client = server.client();
+
+    let first = GetRooms::new().limit(2).send(&client).await.unwrap();
+    assert_eq!(first.total_count(), 3);
+    assert_eq!(first.data().len(), 2);
+    assert!(first.prev_cursor().is_none());
+    let next_cursor = first
+        .next_cursor()
+        .expect("A full page should have a next cursor");
+    assert_eq!(next_cursor.as_str(), "id-2");
+
+    let second = GetRooms::new()
+        .limit(2)
+        .starting_after(next_cursor.clone())
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(second.next_cursor().is_none());
+    assert_eq!(second.prev_cursor().unwrap().as_str(), "id-3");
+    let names: Vec<_> = second.into_iter().map(|room| room.name).collect();
+    assert_eq!(names, ["room-3"]);
+
+    assert!(server.received().starts_with("GET /rooms/?limit=2 "));
+    assert!(server
+        .received()
+        .starts_with("GET /rooms/?limit=2&starting_after=id-2 "));
+}
+
+#[tokio::test]
+async fn get_rooms_stream_filters_by_name_prefix() {
+    let server = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":3,"data":[{},{}]}}"#,
+                room_json("id-1", "tenant-1-a"),
+                room_json("id-2", "tenant-2-a")
+            ),
+        ),
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":3,"data":[{}]}}"#,
+                room_json("id-3", "tenant-1-b")
+            ),
+        ),
+    ]);
+    let client = server.client();
+
+    let rooms: Vec<Room> = GetRooms::new()
+        .limit(2)
+        .name_prefix("tenant-1-")
+        .into_stream(&client)
+        .try_collect()
+        .await
+        .unwrap();
+    let names: Vec<_> = rooms.into_iter().map(|room| room.name).collect();
+    assert_eq!(names, ["tenant-1-a", "tenant-1-b"]);
+
+    // The prefix is never sent to `Daily`
+    assert!(server.received().starts_with("GET /rooms/?limit=2 "));
+    assert!(server
+        .received()
+        .starts_with("GET /rooms/?limit=2&starting_after=id-2 "));
+}
+
+#[tokio::test]
+async fn get_rooms_stream_skips_rooms_seen_on_earlier_pages() {
+    let server = MockServer::start(vec![
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":4,"data":[{},{}]}}"#,
+                room_json("id-1", "room-1"),
+                room_json("id-2", "room-2")
+            ),
+        ),
+        json_response(
+            "200 OK",
+            &format!(
+                r#"{{"total_count":4,"data":[{},{}]}}"#,
+                room_json("id-2", "room-2"),
+                room_json("id-3", "room-3")
+            ),
+        ),
+        json_response("200 OK", r#"{"total_count":4,"data":[]}"#),
+    ]);
+    let client = server.client();
+
+    let rooms: Vec<Room> = GetRooms::new()
+        .limit(2)
+        .into_stream(&client)
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<_> = rooms.into_iter().map(|room| room.id).collect();
+    assert_eq!(ids, ["id-1", "id-2", "id-3"]);
+}
+
+#[tokio::test]
+async fn strict_create_room_checks_created_room() {
+    let created = r#"{"id":"id-1","name":"a-room","api_created":true,"privacy":"private","url":"https://your-domain.daily.co/a-room","created_at":"2019-01-26T09:01:22.000Z","config":{"max_participants":10,"start_audio_off":false}}"#;
+    let server = MockServer::start(vec![
+        json_response("200 OK", created),
+        json_response("200 OK", created),
+        json_response("200 OK", created),
+    ]);
+    let client = server.client();
+
+    let room = CreateRoom::new()
+        .name("a-room")
+        .privacy(RoomPrivacy::Private)
+        .properties(RoomPropertiesBuilder::new().max_participants(10))
+        .strict(true)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(room.privacy, RoomPrivacy::Private);
+
+    let overridden = RoomPropertiesBuilder::new()
+        .max_participants(10)
+        .start_audio_off(true);
+    let err = CreateRoom::new()
+        .properties(overridden.clone())
+        .strict(true)
+        .send(&client)
+        .await
+        .unwrap_err();
+    let Error::InvalidConfig { reason } = err else {
+        panic!("Expected a mismatch error, found {err:?}");
+    };
+    assert!(reason.contains("start_audio_off"));
+
+    // Without strict mode the room is returned as created
+    let room = CreateRoom::new()
+        .properties(overridden)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(room.config.start_audio_off, Some(false));
+}
+
+#[tokio::test]
+async fn strict_create_room_skips_properties_not_returned() {
+    let created = r#"{"id":"id-1","name":"a-room","api_created":true,"privacy":"private","url":"https://your-domain.daily.co/a-room","created_at":"2019-01-26T09:01:22.000Z","config":{"max_participants":10}}"#;
+    let server = MockServer::start(vec![json_response("200 OK", created)]);
+
+    let room = CreateRoom::new()
+        .name("a-room")
+        .properties(
+            RoomPropertiesBuilder::new()
+                .max_participants(10)
+                .enable_chat(true)
+                .extra("enable_emoji_reactions", json!(true)),
+        )
+        .strict(true)
+        .send(&server.client())
+        .await
+        .unwrap();
+    assert_eq!(room.config.enable_chat, None);
+}
+
+#[test]
+fn update_room_sends_only_set_fields() {
+    let mut update = UpdateRoom::new();
+    assert_eq!(serde_json::to_value(&update).unwrap(), json!({}));
+
+    update.properties(RoomPropertiesBuilder::new());
+    assert_eq!(serde_json::to_value(&update).unwrap(), json!({}));
+
+    update.privacy(RoomPrivacy::Private);
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({ "privacy": "private" })
+    );
+
+    update.properties(RoomPropertiesBuilder::new().enable_chat(false));
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({ "privacy": "private", "properties": { "enable_chat": false } })
+    );
+}
+
+#[tokio::test]
+async fn update_room_with_empty_properties_sends_empty_body() {
+    let server = MockServer::start(vec![json_response("200 OK", &room_json("id-1", "a-room"))]);
+
+    UpdateRoom::new()
+        .properties(RoomPropertiesBuilder::new())
+        .send("a-room", &server.client())
+        .await
+        .unwrap();
+    assert!(server.received().ends_with("\r\n\r\n{}"));
+}
@@ -1,20 +1,23 @@
-use dailyco::meeting_token::{CreateMeetingToken, MeetingToken};
-use dailyco::Client;
+use std::time::Duration;
 
-use crate::helpers::get_daily_client;
+use dailyco::configuration::{DailyLang, RecordingType};
+use dailyco::meeting_token::{
+    AdminKind, Allowed, CreateMeetingToken, MediaKind, MeetingToken, TokenPermissions,
+};
+use dailyco::{Client, DailyCoErrorKind, Error};
+
+use serde_json::json;
+
+use crate::helpers::{get_daily_client, json_response, MockServer};
 
 macro_rules! meeting_token {
-    ( $( $field:ident = $value:expr ),* ) => {{
-        let mut builder = CreateMeetingToken::new();
-        $(
-            builder.$field($value);
-        )*
-        builder
-    }};
+    ( $( $field:ident = $value:expr ),* ) => {
+        CreateMeetingToken::new()$(.$field($value))*
+    };
 }
 
-fn get_meeting_token_test_cases(room_name: &str) -> Vec<CreateMeetingToken> {
-    let mut builders = vec![
+fn get_meeting_token_test_cases(room_name: &str) -> Vec<CreateMeetingToken<'_>> {
+    let builders = vec![
         meeting_token! { start_audio_off = true, user_name = "a_user", eject_after_elapsed = 50 },
         meeting_token! {
             start_audio_off = true,
@@ -22,7 +25,7 @@ fn get_meeting_token_test_cases(room_name: &str) -> Vec<CreateMeetingToken> {
             user_id = "hi",
             is_owner = true,
             enable_screenshare = false,
-            exp = chrono::Utc::now().timestamp() + chrono::Duration::hours(3).num_seconds()
+            expires_in = Duration::from_secs(3 * 60 * 60)
         },
         meeting_token! {
             nbf = chrono::Utc::now().timestamp() - chrono::Duration::hours(3).num_seconds(),
@@ -33,10 +36,10 @@ fn get_meeting_token_test_cases(room_name: &str) -> Vec<CreateMeetingToken> {
             lang = dailyco::configuration::DailyLang::Es
         },
     ];
-    builders.iter_mut().for_each(|b| {
-        b.room_name(room_name);
-    });
     builders
+        .into_iter()
+        .map(|b| b.room_name(room_name))
+        .collect()
 }
 
 #[tokio::test]
@@ -45,7 +48,7 @@ async fn meeting_tokens_create_roundtrip() -> anyhow::Result<()> {
     let client = get_daily_client();
     for spec in tokens {
         let token_fetch = spec.send(&client).await?;
-        assert_meeting_token_generation_roundtrip(&client, &token_fetch, spec).await?;
+        assert_meeting_token_generation_roundtrip(&client, token_fetch.as_str(), spec).await?;
     }
     Ok(())
 }
@@ -59,11 +62,40 @@ async fn meeting_tokens_self_sign_roundtrip() -> anyhow::Result<()> {
     let domain_id = crate::helpers::get_domain_id_for_tests();
     for spec in tokens {
         let token = spec.self_sign(&domain_id, &secret_key);
-        assert_meeting_token_generation_roundtrip(&client, &token, spec.clone()).await?;
+        assert_meeting_token_generation_roundtrip(&client, token.as_str(), spec).await?;
     }
     Ok(())
 }
 
+// Catches `Daily` changing the JWT header it expects for self-signed tokens.
+#[tokio::test]
+#[cfg(feature = "self-signed-tokens")]
+async fn meeting_tokens_self_sign_with_header_roundtrip() -> anyhow::Result<()> {
+    use dailyco::meeting_token::{Algorithm, Header};
+
+    let client = get_daily_client();
+    let secret_key = crate::helpers::get_secret_key_for_tests();
+    let domain_id = crate::helpers::get_domain_id_for_tests();
+    let spec = meeting_token!(room_name = "a-room", is_owner = true);
+    let token =
+        spec.self_sign_with_header(&domain_id, &secret_key, &Header::new(Algorithm::HS256))?;
+    assert_meeting_token_generation_roundtrip(&client, token.as_str(), spec).await?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "self-signed-tokens")]
+fn self_sign_with_non_hmac_header() {
+    use dailyco::meeting_token::{Algorithm, Header};
+
+    let res = CreateMeetingToken::new().self_sign_with_header(
+        "domain-id",
+        "secret",
+        &Header::new(Algorithm::RS256),
+    );
+    assert!(matches!(res, Err(Error::InvalidMeetingToken(_))));
+}
+
 async fn assert_meeting_token_generation_roundtrip(
     client: &Client,
     token: &str,
@@ -78,3 +110,387 @@ fn assert_builder_matches_retrieved(expected: CreateMeetingToken, result: Meetin
     let expected: MeetingToken = expected.into();
     assert_eq!(expected, result);
 }
+
+#[test]
+fn relative_timestamps_are_offset_from_now() {
+    let now = chrono::Utc::now().timestamp();
+    let builder = CreateMeetingToken::new()
+        .not_before_in(Duration::from_secs(60))
+        .expires_in(Duration::from_secs(3600));
+
+    let token: MeetingToken = builder.into();
+    let nbf = token.nbf.unwrap();
+    let exp = token.exp.unwrap();
+    assert!((now + 60..=now + 61).contains(&nbf));
+    assert!((now + 3600..=now + 3601).contains(&exp));
+}
+
+#[test]
+fn meeting_tokens_round_trip_through_serde() {
+    for builder in get_meeting_token_test_cases("a-room") {
+        let token: MeetingToken = builder.into();
+        let serialized = serde_json::to_value(&token).unwrap();
+        assert_eq!(
+            serde_json::from_value::<MeetingToken>(serialized).unwrap(),
+            token
+        );
+    }
+}
+
+#[test]
+fn eject_after_rounds_up_to_whole_seconds() {
+    let cases = [
+        (Duration::from_secs(90), 90),
+        (Duration::from_millis(1500), 2),
+        (Duration::from_millis(1), 1),
+        (Duration::ZERO, 0),
+        (Duration::MAX, i64::MAX),
+    ];
+    for (duration, secs) in cases {
+        let token: MeetingToken = CreateMeetingToken::new().eject_after(duration).into();
+        assert_eq!(token.eject_after_elapsed, Some(secs));
+    }
+}
+
+#[test]
+fn builder_converts_to_meeting_token_field_by_field() {
+    let builder = fully_populated_token();
+    let expected = MeetingToken {
+        room_name: Some("a-room".to_string()),
+        eject_at_token_exp: true,
+        eject_after_elapsed: Some(60),
+        nbf: Some(1),
+        exp: Some(2),
+        is_owner: true,
+        user_name: Some("a_user".to_string()),
+        user_id: Some("a_user_id".to_string()),
+        enable_screenshare: false,
+        start_video_off: true,
+        start_audio_off: true,
+        enable_recording: Some(RecordingType::Cloud),
+        enable_prejoin_ui: Some(true),
+        enable_terse_logging: true,
+        start_cloud_recording: true,
+        close_tab_on_exit: true,
+        redirect_on_meeting_exit: Some("https://example.com".to_string()),
+        lang: Some(DailyLang::Fr),
+    };
+    assert_builder_matches_retrieved(builder, expected);
+}
+
+#[test]
+fn default_builder_converts_to_default_meeting_token() {
+    let expected = MeetingToken {
+        enable_screenshare: true,
+        ..MeetingToken::default()
+    };
+    assert_builder_matches_retrieved(CreateMeetingToken::new(), expected);
+}
+
+#[test]
+#[cfg(feature = "self-signed-tokens")]
+fn decode_with_rotated_keys() {
+    use dailyco::meeting_token::decode_with_keys;
+
+    for spec in get_meeting_token_test_cases("a-room") {
+        let token = spec.self_sign("domain-id", "old-secret");
+        let decoded = decode_with_keys(token.as_str(), &["new-secret", "old-secret"]).unwrap();
+        assert_eq!(decoded, MeetingToken::from(spec));
+    }
+}
+
+#[test]
+#[cfg(feature = "self-signed-tokens")]
+fn decode_with_unknown_keys() {
+    use dailyco::meeting_token::decode_with_keys;
+
+    let token = CreateMeetingToken::new()
+        .room_name("a-room")
+        .self_sign("domain-id", "old-secret");
+    let res = decode_with_keys(token.as_str(), &["new-secret", "another-secret"]);
+    assert!(matches!(res, Err(Error::MeetingTokenKeyMismatch)));
+
+    let res = decode_with_keys("not-a-token", &["old-secret"]);
+    assert!(matches!(res, Err(Error::InvalidMeetingToken(_))));
+}
+
+#[test]
+#[cfg(feature = "self-signed-tokens")]
+fn decode_tolerates_clock_skew() {
+    use dailyco::meeting_token::{decode_with_keys, decode_with_keys_and_leeway};
+
+    let now = chrono::Utc::now().timestamp();
+    let cases = [
+        meeting_token!(room_name = "a-room", exp = now - 30),
+        meeting_token!(room_name = "a-room", nbf = now + 30),
+    ];
+    for spec in cases {
+        let token = spec.self_sign("domain-id", "secret");
+        assert!(decode_with_keys(token.as_str(), &["secret"]).is_ok());
+        let res = decode_with_keys_and_leeway(token.as_str(), &["secret"], Duration::ZERO);
+        assert!(matches!(res, Err(Error::InvalidMeetingToken(_))));
+    }
+
+    let expired = meeting_token!(exp = now - 120).self_sign("domain-id", "secret");
+    let res = decode_with_keys(expired.as_str(), &["secret"]);
+    assert!(matches!(res, Err(Error::InvalidMeetingToken(_))));
+}
+
+#[tokio::test]
+async fn contradictory_tokens_are_rejected_locally() {
+    let server = MockServer::start(vec![]);
+    let client = server.client();
+    let cases = [
+        meeting_token!(nbf = 20, exp = 10),
+        meeting_token!(nbf = 10, exp = 10),
+        meeting_token!(eject_after_elapsed = -1),
+    ];
+    for spec in cases {
+        assert!(matches!(spec.validate(), Err(Error::InvalidConfig { .. })));
+        let res = spec.send(&client).await;
+        assert!(matches!(res, Err(Error::InvalidConfig { .. })));
+    }
+
+    assert!(meeting_token!(nbf = 10, exp = 20, eject_after_elapsed = 0)
+        .validate()
+        .is_ok());
+}
+
+#[tokio::test]
+async fn expired_tokens_are_told_apart_from_invalid_ones() {
+    let server = MockServer::start(vec![
+        json_response(
+            "400 Bad Request",
+            r#"{"error":"invalid-request-error","info":"token expired"}"#,
+        ),
+        json_response(
+            "400 Bad Request",
+            r#"{"error":"invalid-request-error","info":"malformed token"}"#,
+        ),
+    ]);
+    let client = server.client();
+
+    let kind = |res: dailyco::Result<MeetingToken>| match res {
+        Err(Error::APIError(info)) => info.error,
+        res => panic!("Expected API error, found {:?}", res),
+    };
+    let expired = kind(client.get_meeting_token("expired").await).unwrap();
+    assert_eq!(expired, DailyCoErrorKind::TokenExpired);
+    assert_eq!(expired.as_str(), "invalid-request-error");
+    assert_eq!(expired.to_string(), "invalid-request-error (token expired)");
+    assert_eq!(
+        kind(client.get_meeting_token("malformed").await),
+        Some(DailyCoErrorKind::InvalidRequestError)
+    );
+}
+
+fn fully_populated_token() -> CreateMeetingToken<'static> {
+    meeting_token! {
+        room_name = "a-room",
+        eject_at_token_exp = true,
+        eject_after_elapsed = 60,
+        nbf = 1,
+        exp = 2,
+        is_owner = true,
+        user_name = "a_user",
+        user_id = "a_user_id",
+        enable_screenshare = false,
+        start_video_off = true,
+        start_audio_off = true,
+        enable_recording = RecordingType::Cloud,
+        enable_prejoin_ui = true,
+        enable_terse_logging = true,
+        start_cloud_recording = true,
+        close_tab_on_exit = true,
+        redirect_on_meeting_exit = "https://example.com",
+        lang = DailyLang::Fr
+    }
+}
+
+// Locks the wire format, as `create_room_wire_format` does for rooms.
+#[tokio::test]
+async fn create_meeting_token_wire_format() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        r#"{"token":"header.payload.signature"}"#,
+    )]);
+
+    fully_populated_token()
+        .send(&server.client())
+        .await
+        .unwrap();
+    let request = server.received();
+    let body: serde_json::Value =
+        serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "properties": {
+                "room_name": "a-room",
+                "eject_at_token_exp": true,
+                "eject_after_elapsed": 60,
+                "nbf": 1,
+                "exp": 2,
+                "is_owner": true,
+                "user_name": "a_user",
+                "user_id": "a_user_id",
+                "enable_screenshare": false,
+                "start_video_off": true,
+                "start_audio_off": true,
+                "enable_recording": "cloud",
+                "enable_prejoin_ui": true,
+                "enable_terse_logging": true,
+                "start_cloud_recording": true,
+                "close_tab_on_exit": true,
+                "redirect_on_meeting_exit": "https://example.com",
+                "lang": "fr",
+            }
+        })
+    );
+}
+
+// `Daily` expects self-signed tokens to use its abbreviated claim names.
+#[test]
+#[cfg(feature = "self-signed-tokens")]
+fn self_signed_token_wire_format() {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+
+    let token = fully_populated_token().self_sign("domain-id", "secret");
+    let payload = token.as_str().split('.').nth(1).unwrap();
+    let claims: serde_json::Value =
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+    assert_eq!(
+        claims,
+        json!({
+            "d": "domain-id",
+            "r": "a-room",
+            "ejt": true,
+            "eje": 60,
+            "nbf": 1,
+            "exp": 2,
+            "o": true,
+            "u": "a_user",
+            "ud": "a_user_id",
+            "ss": false,
+            "vo": true,
+            "ao": true,
+            "er": "cloud",
+            "enable_prejoin_ui": true,
+            "enable_terse_logging": true,
+            "sr": true,
+            "ctoe": true,
+            "rome": "https://example.com",
+            "uil": "fr",
+        })
+    );
+}
+
+#[tokio::test]
+async fn created_tokens_come_with_their_claims() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        r#"{"token":"header.payload.signature"}"#,
+    )]);
+    let spec = meeting_token!(room_name = "a-room", is_owner = true, exp = 1234);
+
+    let (token, claims) = spec.send_with_claims(&server.client()).await.unwrap();
+    assert_eq!(token.as_str(), "header.payload.signature");
+    assert_eq!(claims, MeetingToken::from(spec));
+    assert_eq!(claims.exp, Some(1234));
+}
+
+#[tokio::test]
+async fn tokens_are_validated_in_order() {
+    let server = MockServer::start(vec![
+        json_response("200 OK", r#"{"room_name":"a-room","is_owner":true}"#),
+        json_response(
+            "400 Bad Request",
+            r#"{"error":"invalid-request-error","info":"token expired"}"#,
+        ),
+    ]);
+    let client = server.client();
+
+    let results = client.validate_tokens(&["valid", "expired"], 1).await;
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().unwrap().room_name.as_deref(),
+        Some("a-room")
+    );
+    assert!(results[1].is_err());
+    assert!(server.received().starts_with("GET /meeting-tokens/valid "));
+    assert!(server
+        .received()
+        .starts_with("GET /meeting-tokens/expired "));
+}
+
+#[tokio::test]
+#[cfg(feature = "self-signed-tokens")]
+async fn self_signed_tokens_are_validated_locally() {
+    let server = MockServer::start(vec![json_response(
+        "200 OK",
+        r#"{"room_name":"another-room"}"#,
+    )]);
+    let client = server.client();
+    let self_signed = meeting_token!(room_name = "a-room").self_sign("domain-id", "secret");
+    let now = chrono::Utc::now().timestamp();
+    let expired = meeting_token!(exp = now - 120).self_sign("domain-id", "secret");
+    let other = meeting_token!(room_name = "another-room").self_sign("domain-id", "other");
+
+    let results = client
+        .validate_tokens_with_keys(
+            &[self_signed.as_str(), expired.as_str(), other.as_str()],
+            &["secret"],
+            2,
+        )
+        .await;
+    assert_eq!(
+        results[0].as_ref().unwrap().room_name.as_deref(),
+        Some("a-room")
+    );
+    assert!(matches!(results[1], Err(Error::InvalidMeetingToken(_))));
+    assert_eq!(
+        results[2].as_ref().unwrap().room_name.as_deref(),
+        Some("another-room")
+    );
+    let request = server.received();
+    assert!(request.starts_with(&format!("GET /meeting-tokens/{} ", other.as_str())));
+}
+
+#[tokio::test]
+async fn participant_permissions_are_updated_by_session_id() {
+    let server = MockServer::start(vec![json_response("200 OK", r#"{"status":"ok"}"#)]);
+    let client = server.client();
+    let permissions = TokenPermissions {
+        has_presence: Some(true),
+        can_send: Some(Allowed::Only(vec![
+            MediaKind::Audio,
+            MediaKind::ScreenVideo,
+        ])),
+        can_admin: Some(Allowed::All(false)),
+    };
+
+    client
+        .update_participant("a-room", "a-session-id", &permissions)
+        .await
+        .unwrap();
+    let request = server.received();
+    assert!(request.starts_with("POST /rooms/a-room/update-permissions "));
+    assert!(request.ends_with(
+        r#"{"data":{"a-session-id":{"hasPresence":true,"canSend":["audio","screenVideo"],"canAdmin":false}}}"#
+    ));
+    assert_eq!(
+        serde_json::from_str::<TokenPermissions>(
+            r#"{"canSend":true,"canAdmin":["participants","transcription"]}"#
+        )
+        .unwrap(),
+        TokenPermissions {
+            has_presence: None,
+            can_send: Some(Allowed::All(true)),
+            can_admin: Some(Allowed::Only(vec![
+                AdminKind::Participants,
+                AdminKind::Transcription
+            ])),
+        }
+    );
+}